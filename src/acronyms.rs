@@ -0,0 +1,222 @@
+use crate::utils;
+
+/// Controls how registered acronyms are written by the outputs that capitalize words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AcronymStyle {
+    /// Acronyms are capitalized like any other word: `HttpServer`, `userId`.
+    #[default]
+    Flattened,
+    /// Acronyms keep the spelling they were registered with: `HTTPServer`, `userID`.
+    /// An acronym starting a `camelCase` output is still lowercased: `idValue`.
+    Canonical,
+    /// Registered acronyms keep their spelling, and words of two letters written in capitals in the input stay
+    /// capitalized, following the .NET guidelines: `IOStream`, `XmlReader`.
+    TwoLetterUpper,
+}
+
+/// A registry of known acronyms. A registered acronym is never split apart when the input is sliced into
+/// words, and can be written with its canonical spelling depending on the [`AcronymStyle`].
+/// # Example
+/// ```
+/// use recase::{AcronymStyle, Acronyms, Options, ReCase};
+///
+/// let acronyms = Acronyms::new().with("OAuth").with("ID");
+/// let options = Options::new().acronyms(acronyms).acronym_style(AcronymStyle::Canonical);
+/// let recase = ReCase::with_options("OAuthTokenId", options);
+/// assert_eq!(recase.snake_case(), String::from("oauth_token_id"));
+/// assert_eq!(recase.pascal_case(), String::from("OAuthTokenID"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Acronyms {
+    // (lowercased, canonical, uppercased), longest first
+    entries: Vec<(String, String, String)>,
+}
+
+impl Acronyms {
+    /// The common initialisms recognized by golint.
+    pub const GO_INITIALISMS: [&'static str; 38] = [
+        "ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GUID", "HTML", "HTTP", "HTTPS", "ID",
+        "IP", "JSON", "LHS", "QPS", "RAM", "RHS", "RPC", "SLA", "SMTP", "SQL", "SSH", "TCP", "TLS",
+        "TTL", "UDP", "UI", "UID", "UUID", "URI", "URL", "UTF8", "VM", "XML", "XMPP", "XSRF",
+        "XSS",
+    ];
+
+    /// Create an empty registry.
+    pub fn new() -> Acronyms {
+        Acronyms::default()
+    }
+
+    /// Create a registry holding the compound words that the .NET guidelines do not treat as acronyms,
+    /// `Id` and `Ok`.
+    pub fn dotnet() -> Acronyms {
+        Acronyms::new().with("Id").with("Ok")
+    }
+
+    /// Create a registry holding the [`GO_INITIALISMS`](Acronyms::GO_INITIALISMS).
+    /// ## Example
+    /// ```
+    /// let acronyms = recase::Acronyms::go().with("GRPC");
+    /// assert_eq!(acronyms.get("grpc"), Some("GRPC"));
+    /// assert_eq!(acronyms.get("json"), Some("JSON"));
+    /// ```
+    pub fn go() -> Acronyms {
+        Acronyms::GO_INITIALISMS
+            .iter()
+            .fold(Acronyms::new(), |acronyms, initialism| {
+                acronyms.with(*initialism)
+            })
+    }
+
+    /// Adds an acronym with its canonical spelling, e.g. `HTTP` or `GraphQL`.
+    /// Registering an acronym again replaces its canonical spelling.
+    pub fn with<S: Into<String>>(mut self, acronym: S) -> Acronyms {
+        let canonical = acronym.into();
+        if canonical.is_empty() {
+            return self;
+        }
+        let lowercased = utils::lowercase(&canonical);
+        let uppercased = canonical.to_uppercase();
+
+        self.entries.retain(|(l, _, _)| *l != lowercased);
+        let position = self
+            .entries
+            .iter()
+            .position(|(_, c, _)| c.len() < canonical.len())
+            .unwrap_or(self.entries.len());
+        self.entries
+            .insert(position, (lowercased, canonical, uppercased));
+        self
+    }
+
+    /// Removes an acronym, whatever its case.
+    pub fn without(mut self, acronym: &str) -> Acronyms {
+        let lowercased = utils::lowercase(acronym);
+        self.entries.retain(|(l, _, _)| *l != lowercased);
+        self
+    }
+
+    /// Returns the canonical spelling of a word if it is a registered acronym, whatever its case.
+    /// ## Example
+    /// ```
+    /// let acronyms = recase::Acronyms::new().with("GraphQL");
+    /// assert_eq!(acronyms.get("graphql"), Some("GraphQL"));
+    /// assert_eq!(acronyms.get("graph"), None);
+    /// ```
+    pub fn get(&self, word: &str) -> Option<&str> {
+        let lowercased = utils::lowercase(word);
+        self.entries
+            .iter()
+            .find(|(l, _, _)| *l == lowercased)
+            .map(|(_, c, _)| c.as_str())
+    }
+
+    /// Returns true if no acronym is registered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the byte lengths of the acronyms found at the start of `input`, longest first, written either
+    /// in their canonical spelling or in uppercase.
+    pub(crate) fn match_prefix<'a>(&'a self, input: &'a str) -> impl Iterator<Item = usize> + 'a {
+        self.entries
+            .iter()
+            .filter_map(move |(_, canonical, uppercased)| {
+                if input.starts_with(canonical.as_str()) {
+                    return Some(canonical.len());
+                }
+                input
+                    .starts_with(uppercased.as_str())
+                    .then_some(uppercased.len())
+            })
+    }
+}
+
+#[cfg(test)]
+mod acronyms_tests {
+    use crate::{AcronymStyle, Acronyms, Casing, Options, ReCase, Segmentation};
+
+    fn options() -> Options {
+        let acronyms = Acronyms::new()
+            .with("HTTP")
+            .with("URL")
+            .with("ID")
+            .with("OAuth")
+            .with("GraphQL");
+        Options::new().acronyms(acronyms)
+    }
+
+    #[test]
+    fn test_registry() {
+        let acronyms = Acronyms::new().with("ID").with("HTTPS").with("Id");
+        assert_eq!(acronyms.get("ID"), Some("Id"));
+        assert_eq!(acronyms.get("https"), Some("HTTPS"));
+
+        let acronyms = acronyms.without("id");
+        assert_eq!(acronyms.get("id"), None);
+        assert!(Acronyms::new().with("").is_empty());
+    }
+
+    #[test]
+    fn test_acronyms_are_not_split() {
+        let input = [
+            ("HTTPServer", "http server"),
+            ("OAuthToken", "oauth token"),
+            ("GraphQLSchema", "graphql schema"),
+            ("userIDValue", "user id value"),
+            ("getURL", "get url"),
+            ("XMLHTTP_URL", "x m l http url"),
+            ("IDENTITY", "i d e n t i t y"),
+            ("Identity", "identity"),
+            ("HTTPǅungla", "http ǆungla"),
+        ];
+
+        for (s, expected) in input {
+            assert_eq!(s.recase_with(options()).normal_case(), expected);
+        }
+
+        let options = options().segmentation(Segmentation::Acronym);
+        assert_eq!(
+            "IDENTITY".recase_with(options.clone()).normal_case(),
+            "identity"
+        );
+        assert_eq!(
+            "HTTPIDValue".recase_with(options.clone()).normal_case(),
+            "http id value"
+        );
+        assert_eq!(
+            "MYHTTPServer".recase_with(options).normal_case(),
+            "myhttp server"
+        );
+    }
+
+    #[test]
+    fn test_canonical_style() {
+        let options = options().acronym_style(AcronymStyle::Canonical);
+
+        let recase = ReCase::with_options("http_server", options.clone());
+        assert_eq!(recase.pascal_case(), "HTTPServer");
+        assert_eq!(recase.camel_case(), "httpServer");
+        assert_eq!(recase.title_case(), "HTTP Server");
+        assert_eq!(recase.snake_case(), "http_server");
+
+        let recase = ReCase::with_options("user id", options.clone());
+        assert_eq!(recase.camel_case(), "userID");
+        assert_eq!(recase.sentence_case(), "User ID");
+
+        let recase = ReCase::with_options("IDValue", options.clone());
+        assert_eq!(recase.camel_case(), "idValue");
+        assert_eq!(recase.header_case(), "ID-Value");
+
+        let recase = ReCase::with_options("graphql oauth", options);
+        assert_eq!(recase.pascal_case(), "GraphQLOAuth");
+    }
+
+    #[test]
+    fn test_flattened_style() {
+        let recase = ReCase::with_options("HTTPServer", options());
+        assert_eq!(recase.pascal_case(), "HttpServer");
+
+        let recase = ReCase::with_options("userID", options());
+        assert_eq!(recase.camel_case(), "userId");
+    }
+}
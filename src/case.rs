@@ -0,0 +1,255 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A convention case supported by [`ReCase`](crate::ReCase), to choose the conversion at runtime.
+///
+/// A Case is displayed with its canonical name (e.g. `snake_case` or `Title Case`), and can be parsed from it or
+/// from a common alias (e.g. `snake`, `SCREAMING_SNAKE`, `kebab` or `train`), whatever the case and separators.
+/// # Example
+/// ```
+/// use recase::{Case, ReCase};
+///
+/// let case: Case = "SCREAMING_SNAKE".parse().unwrap();
+/// assert_eq!(case, Case::UpperSnake);
+/// assert_eq!(case.to_string(), String::from("UPPER_SNAKE_CASE"));
+/// assert_eq!(ReCase::new("Example String").to_case(case), String::from("EXAMPLE_STRING"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `normal case`
+    Normal,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+    /// `snake_case`
+    Snake,
+    /// `kebab-case`
+    Kebab,
+    /// `dot.case`
+    Dot,
+    /// `path/case`
+    Path,
+    /// `windows\path\case`
+    WindowsPath,
+    /// `Sentence case`
+    Sentence,
+    /// `Title Case`
+    Title,
+    /// `Header-Case`
+    Header,
+    /// `UPPER_SNAKE_CASE`
+    UpperSnake,
+    /// `flatcase`
+    Flat,
+    /// `UPPERFLATCASE`
+    UpperFlat,
+    /// `COBOL-CASE`
+    Cobol,
+    /// `UPPER.DOT.CASE`
+    UpperDot,
+    /// `Ada_Case`
+    Ada,
+    /// `camel_Snake_Case`
+    CamelSnake,
+    /// `AlTeRnAtInG cAsE`
+    Alternating,
+}
+
+impl Case {
+    /// Every supported case, in the order of the README.
+    pub const ALL: [Case; 19] = [
+        Case::Camel,
+        Case::Snake,
+        Case::Pascal,
+        Case::Kebab,
+        Case::Dot,
+        Case::Path,
+        Case::WindowsPath,
+        Case::Normal,
+        Case::Title,
+        Case::Sentence,
+        Case::Header,
+        Case::UpperSnake,
+        Case::Flat,
+        Case::UpperFlat,
+        Case::Cobol,
+        Case::UpperDot,
+        Case::Ada,
+        Case::CamelSnake,
+        Case::Alternating,
+    ];
+
+    /// Returns an iterator over every supported case.
+    /// ## Example
+    /// ```
+    /// let names: Vec<String> = recase::Case::all().map(|case| case.to_string()).collect();
+    /// assert_eq!(names[0], String::from("camelCase"));
+    /// ```
+    pub fn all() -> impl Iterator<Item = Case> {
+        Case::ALL.into_iter()
+    }
+
+    /// Returns the canonical name of the case.
+    pub fn name(self) -> &'static str {
+        match self {
+            Case::Normal => "normal case",
+            Case::Camel => "camelCase",
+            Case::Pascal => "PascalCase",
+            Case::Snake => "snake_case",
+            Case::Kebab => "kebab-case",
+            Case::Dot => "dot.case",
+            Case::Path => "path/case",
+            Case::WindowsPath => "windows\\path\\case",
+            Case::Sentence => "Sentence case",
+            Case::Title => "Title Case",
+            Case::Header => "Header-Case",
+            Case::UpperSnake => "UPPER_SNAKE_CASE",
+            Case::Flat => "flatcase",
+            Case::UpperFlat => "UPPERFLATCASE",
+            Case::Cobol => "COBOL-CASE",
+            Case::UpperDot => "UPPER.DOT.CASE",
+            Case::Ada => "Ada_Case",
+            Case::CamelSnake => "camel_Snake_Case",
+            Case::Alternating => "AlTeRnAtInG cAsE",
+        }
+    }
+
+    /// Returns the names the case can be parsed from, lowercased and without separators.
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Case::Normal => &[
+                "normal",
+                "normalcase",
+                "lower",
+                "lowercase",
+                "lowersentence",
+                "lowersentencecase",
+            ],
+            Case::Camel => &["camel", "camelcase", "lowercamel", "lowercamelcase"],
+            Case::Pascal => &["pascal", "pascalcase", "uppercamel", "uppercamelcase"],
+            Case::Snake => &["snake", "snakecase"],
+            Case::Kebab => &["kebab", "kebabcase", "dash", "dashcase"],
+            Case::Dot => &["dot", "dotcase"],
+            Case::Path => &["path", "pathcase"],
+            Case::WindowsPath => &["windowspath", "windowspathcase", "backslash"],
+            Case::Sentence => &["sentence", "sentencecase"],
+            Case::Title => &["title", "titlecase"],
+            Case::Header => &["header", "headercase", "train", "traincase"],
+            Case::UpperSnake => &[
+                "uppersnake",
+                "uppersnakecase",
+                "screamingsnake",
+                "screamingsnakecase",
+                "constant",
+                "constantcase",
+            ],
+            Case::Flat => &["flat", "flatcase"],
+            Case::UpperFlat => &["upperflat", "upperflatcase"],
+            Case::Cobol => &[
+                "cobol",
+                "cobolcase",
+                "screamingkebab",
+                "screamingkebabcase",
+                "upperkebab",
+                "upperkebabcase",
+            ],
+            Case::UpperDot => &["upperdot", "upperdotcase"],
+            Case::Ada => &["ada", "adacase", "pascalsnake", "pascalsnakecase"],
+            Case::CamelSnake => &["camelsnake", "camelsnakecase"],
+            Case::Alternating => &["alternating", "alternatingcase"],
+        }
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Case {
+    type Err = ParseCaseError;
+
+    fn from_str(s: &str) -> Result<Case, ParseCaseError> {
+        let normalized: String = s
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '_' | '-' | '.' | '/' | '\\'))
+            .flat_map(char::to_lowercase)
+            .collect();
+
+        Case::all()
+            .find(|case| case.aliases().contains(&normalized.as_str()))
+            .ok_or_else(|| ParseCaseError {
+                input: s.to_owned(),
+            })
+    }
+}
+
+/// The error returned when parsing an unknown [`Case`] name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCaseError {
+    input: String,
+}
+
+impl fmt::Display for ParseCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown case name: \"{}\"", self.input)
+    }
+}
+
+impl Error for ParseCaseError {}
+
+#[cfg(test)]
+mod case_tests {
+    use crate::{Case, Casing, ReCase};
+
+    #[test]
+    fn test_names_round_trip() {
+        for case in Case::all() {
+            assert_eq!(case.to_string().parse::<Case>(), Ok(case));
+        }
+        assert_eq!(Case::all().count(), 19);
+    }
+
+    #[test]
+    fn test_aliases() {
+        let input = [
+            ("snake", Case::Snake),
+            ("snake_case", Case::Snake),
+            ("SCREAMING_SNAKE", Case::UpperSnake),
+            ("kebab", Case::Kebab),
+            ("train", Case::Header),
+            ("Train-Case", Case::Header),
+            ("camel", Case::Camel),
+            ("PASCAL", Case::Pascal),
+            ("windows path", Case::WindowsPath),
+            ("SCREAMING-KEBAB", Case::Cobol),
+            ("Pascal_Snake_Case", Case::Ada),
+            ("lower sentence", Case::Normal),
+            ("UPPERFLATCASE", Case::UpperFlat),
+        ];
+
+        for (name, case) in input {
+            assert_eq!(name.parse::<Case>(), Ok(case));
+        }
+
+        let error = "snek".parse::<Case>().unwrap_err();
+        assert_eq!(error.to_string(), "unknown case name: \"snek\"");
+    }
+
+    #[test]
+    fn test_to_case() {
+        let recase = ReCase::new("Example String");
+        assert_eq!(recase.to_case(Case::Snake), recase.snake_case());
+        assert_eq!(recase.to_case(Case::WindowsPath), "example\\string");
+        assert_eq!(recase.to_case(Case::Alternating), "eXaMpLe StRiNg");
+
+        assert_eq!("Example String".to_case(Case::Header), "Example-String");
+        assert_eq!(
+            String::from("Example String").to_case(Case::Dot),
+            "example.string"
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::detect::WordShape;
+use crate::{utils, Options};
+
+/// The casing of a word in a [`CustomCase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WordCase {
+    /// `word`
+    Lower,
+    /// `WORD`
+    Upper,
+    /// `Word`, or the spelling of a registered acronym.
+    Title,
+}
+
+/// A convention case defined at runtime by a [`CaseBuilder`], to be applied with
+/// [`ReCase::custom_case()`](crate::ReCase::custom_case).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CustomCase {
+    pub(crate) separator: String,
+    pub(crate) first_word: WordCase,
+    pub(crate) other_words: WordCase,
+    pub(crate) prefix: String,
+    pub(crate) suffix: String,
+}
+
+impl CustomCase {
+    /// Infers a case from an example written in it: its separator, the casing of its first and other words, and
+    /// the text before its first word and after its last one. Runs of capitals are words, so `HTTP_Server` is
+    /// written with an uppercase first word.
+    ///
+    /// The example must have at least two words, separated consistently, and its words after the first one must
+    /// share the same casing.
+    /// ## Example
+    /// ```
+    /// use recase::{CustomCase, InferCaseError, ReCase, WordCase};
+    ///
+    /// let case = CustomCase::from_example("Foo__Bar").unwrap();
+    /// assert_eq!(case.separator(), "__");
+    /// assert_eq!(case.first_word(), WordCase::Title);
+    /// assert_eq!(ReCase::new("example string").custom_case(&case), String::from("Example__String"));
+    ///
+    /// assert_eq!(CustomCase::from_example("foo"), Err(InferCaseError::SingleWord));
+    /// ```
+    pub fn from_example(example: &str) -> Result<CustomCase, InferCaseError> {
+        let options = Options::detection();
+        let spans = utils::slice_into_spans(example, &options);
+        if spans.len() < 2 {
+            return Err(InferCaseError::SingleWord);
+        }
+
+        let separator = &example[spans[0].end..spans[1].start];
+        if let Some(other) = spans
+            .windows(2)
+            .map(|pair| &example[pair[0].end..pair[1].start])
+            .find(|&gap| gap != separator)
+        {
+            return Err(InferCaseError::InconsistentSeparators {
+                first: separator.to_owned(),
+                other: other.to_owned(),
+            });
+        }
+
+        let word_case = |span: &std::ops::Range<usize>| {
+            let word = &example[span.clone()];
+            match WordShape::of(word) {
+                WordShape::Lower => Ok(Some(WordCase::Lower)),
+                WordShape::Upper => Ok(Some(WordCase::Upper)),
+                WordShape::Title => Ok(Some(WordCase::Title)),
+                // a single capital is either uppercase or titlecase, and is decided by the other words
+                WordShape::Capital | WordShape::Uncased => Ok(None),
+                WordShape::Mixed => Err(InferCaseError::InconsistentCasing {
+                    word: word.to_owned(),
+                }),
+            }
+        };
+
+        let first_word = word_case(&spans[0])?;
+        let mut other_words = None;
+        for span in &spans[1..] {
+            match (other_words, word_case(span)?) {
+                (Some(expected), Some(found)) if expected != found => {
+                    return Err(InferCaseError::InconsistentCasing {
+                        word: example[span.clone()].to_owned(),
+                    })
+                }
+                (None, found) => other_words = found,
+                _ => {}
+            }
+        }
+
+        let is_capital = |span: &std::ops::Range<usize>| {
+            WordShape::of(&example[span.clone()]) == WordShape::Capital
+        };
+        let undecided = |span: &std::ops::Range<usize>| {
+            if is_capital(span) {
+                Ok(WordCase::Title)
+            } else {
+                Err(InferCaseError::Uncased)
+            }
+        };
+        let (first_word, other_words) = match (first_word, other_words) {
+            (Some(first), Some(other)) => (first, other),
+            (Some(first), None) if spans[1..].iter().any(is_capital) => (first, WordCase::Title),
+            (Some(first), None) => (first, first),
+            (None, Some(other)) if is_capital(&spans[0]) && other == WordCase::Lower => {
+                (WordCase::Title, other)
+            }
+            (None, Some(other)) => (other, other),
+            (None, None) => {
+                let first = undecided(&spans[0])?;
+                (first, undecided(&spans[1]).unwrap_or(first))
+            }
+        };
+
+        Ok(CustomCase {
+            separator: separator.to_owned(),
+            first_word,
+            other_words,
+            prefix: example[..spans[0].start].to_owned(),
+            suffix: example[spans[spans.len() - 1].end..].to_owned(),
+        })
+    }
+
+    /// Returns the separator written between words.
+    pub fn separator(&self) -> &str {
+        &self.separator
+    }
+
+    /// Returns the casing of the first word.
+    pub fn first_word(&self) -> WordCase {
+        self.first_word
+    }
+
+    /// Returns the casing of the words after the first one.
+    pub fn other_words(&self) -> WordCase {
+        self.other_words
+    }
+
+    /// Returns the text written before the first word.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns the text written after the last word.
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+}
+
+/// The error returned when a [`CustomCase`] can't be inferred from an example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InferCaseError {
+    /// The example has less than two words, so its separator is unknown.
+    SingleWord,
+    /// The words of the example are not all separated by the same separator.
+    InconsistentSeparators {
+        /// The separator between the first two words.
+        first: String,
+        /// The first separator that differs from it.
+        other: String,
+    },
+    /// A word is neither lowercase, uppercase nor titlecase, or a word after the first one is not cased like the
+    /// previous ones.
+    InconsistentCasing {
+        /// The word.
+        word: String,
+    },
+    /// The example has no cased letters.
+    Uncased,
+}
+
+impl fmt::Display for InferCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InferCaseError::SingleWord => {
+                f.write_str("the example must have at least two words to show their separator")
+            }
+            InferCaseError::InconsistentSeparators { first, other } => write!(
+                f,
+                "the example separates words with both \"{first}\" and \"{other}\""
+            ),
+            InferCaseError::InconsistentCasing { word } => write!(
+                f,
+                "the word \"{word}\" is not cased like the previous words"
+            ),
+            InferCaseError::Uncased => f.write_str("the example has no cased letters"),
+        }
+    }
+}
+
+impl Error for InferCaseError {}
+
+/// Builds a [`CustomCase`] from a separator, the casing of the first and other words, and an optional prefix and
+/// suffix. Without any setting, it builds `snake_case`.
+/// # Example
+/// ```
+/// use recase::{CaseBuilder, ReCase, WordCase};
+///
+/// let ada_case = CaseBuilder::new()
+///     .first_word(WordCase::Title)
+///     .other_words(WordCase::Title)
+///     .build();
+/// let recase = ReCase::new("example string");
+/// assert_eq!(recase.custom_case(&ada_case), String::from("Example_String"));
+///
+/// let custom = CaseBuilder::new()
+///     .separator("__")
+///     .first_word(WordCase::Upper)
+///     .other_words(WordCase::Title)
+///     .prefix("X_")
+///     .build();
+/// assert_eq!(recase.custom_case(&custom), String::from("X_EXAMPLE__String"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CaseBuilder {
+    case: CustomCase,
+}
+
+impl CaseBuilder {
+    /// Create a new builder of `snake_case`.
+    pub fn new() -> CaseBuilder {
+        CaseBuilder {
+            case: CustomCase {
+                separator: String::from("_"),
+                first_word: WordCase::Lower,
+                other_words: WordCase::Lower,
+                prefix: String::new(),
+                suffix: String::new(),
+            },
+        }
+    }
+
+    /// Sets the separator written between words, which can be empty.
+    pub fn separator<S: Into<String>>(mut self, separator: S) -> CaseBuilder {
+        self.case.separator = separator.into();
+        self
+    }
+
+    /// Sets the casing of the first word.
+    pub fn first_word(mut self, word_case: WordCase) -> CaseBuilder {
+        self.case.first_word = word_case;
+        self
+    }
+
+    /// Sets the casing of the words after the first one.
+    pub fn other_words(mut self, word_case: WordCase) -> CaseBuilder {
+        self.case.other_words = word_case;
+        self
+    }
+
+    /// Sets the text written before the first word.
+    pub fn prefix<S: Into<String>>(mut self, prefix: S) -> CaseBuilder {
+        self.case.prefix = prefix.into();
+        self
+    }
+
+    /// Sets the text written after the last word.
+    pub fn suffix<S: Into<String>>(mut self, suffix: S) -> CaseBuilder {
+        self.case.suffix = suffix.into();
+        self
+    }
+
+    /// Returns the case defined by the builder.
+    pub fn build(self) -> CustomCase {
+        self.case
+    }
+}
+
+impl Default for CaseBuilder {
+    fn default() -> CaseBuilder {
+        CaseBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod custom_tests {
+    use crate::{CaseBuilder, Casing, CustomCase, InferCaseError, Options, ReCase, WordCase};
+
+    #[test]
+    fn test_custom_cases() {
+        let recase = ReCase::new("göd mätsuri example");
+        let input = [
+            (CaseBuilder::new(), "göd_mätsuri_example"),
+            (
+                CaseBuilder::new()
+                    .separator("-")
+                    .first_word(WordCase::Upper)
+                    .other_words(WordCase::Upper),
+                "GÖD-MÄTSURI-EXAMPLE",
+            ),
+            (
+                CaseBuilder::new().other_words(WordCase::Title),
+                "göd_Mätsuri_Example",
+            ),
+            (
+                CaseBuilder::new()
+                    .separator("")
+                    .first_word(WordCase::Title)
+                    .other_words(WordCase::Title)
+                    .prefix("I")
+                    .suffix("Ext"),
+                "IGödMätsuriExampleExt",
+            ),
+        ];
+
+        for (builder, expected) in input {
+            assert_eq!(recase.custom_case(&builder.build()), expected);
+        }
+
+        let prefixed = CaseBuilder::new().prefix("X_").suffix("_T").build();
+        assert_eq!(ReCase::new("").custom_case(&prefixed), "");
+        assert_eq!(ReCase::new("__").custom_case(&prefixed), "");
+        assert_eq!(ReCase::new("foo").custom_case(&prefixed), "X_foo_T");
+    }
+
+    #[test]
+    fn test_from_example() {
+        let recase = ReCase::new("göd mätsuri example");
+        let input = [
+            ("Foo__Bar", "Göd__Mätsuri__Example"),
+            ("fooBar", "gödMätsuriExample"),
+            ("FOO-BAR-BAZ", "GÖD-MÄTSURI-EXAMPLE"),
+            ("HTTP_Server", "GÖD_Mätsuri_Example"),
+            ("A_b", "Göd_mätsuri_example"),
+            ("x_Y", "göd_Mätsuri_Example"),
+            ("X_Y", "Göd_Mätsuri_Example"),
+            ("foo_2", "göd_mätsuri_example"),
+            ("__foo.bar__", "__göd.mätsuri.example__"),
+            ("Äpfel und birnen", "Göd mätsuri example"),
+        ];
+
+        for (example, expected) in input {
+            let case = CustomCase::from_example(example).unwrap();
+            assert_eq!(recase.custom_case(&case), expected, "{example}");
+        }
+    }
+
+    #[test]
+    fn test_from_example_errors() {
+        let input = [
+            ("", InferCaseError::SingleWord),
+            ("__foo__", InferCaseError::SingleWord),
+            (
+                "foo_bar-baz",
+                InferCaseError::InconsistentSeparators {
+                    first: String::from("_"),
+                    other: String::from("-"),
+                },
+            ),
+            (
+                "foo_barBaz",
+                InferCaseError::InconsistentSeparators {
+                    first: String::from("_"),
+                    other: String::new(),
+                },
+            ),
+            (
+                "foo_Bar_baz",
+                InferCaseError::InconsistentCasing {
+                    word: String::from("baz"),
+                },
+            ),
+            ("1_2", InferCaseError::Uncased),
+        ];
+
+        for (example, expected) in input {
+            assert_eq!(CustomCase::from_example(example), Err(expected));
+        }
+
+        assert_eq!(
+            InferCaseError::SingleWord.to_string(),
+            "the example must have at least two words to show their separator"
+        );
+    }
+
+    #[test]
+    fn test_custom_case_with_options() {
+        let camel_snake = CaseBuilder::new().other_words(WordCase::Title).build();
+
+        let recase = ReCase::with_options("user id", Options::go());
+        assert_eq!(recase.custom_case(&camel_snake), "user_ID");
+
+        assert_eq!("".to_custom_case(&camel_snake), "");
+        assert_eq!(
+            "Example String".to_custom_case(&camel_snake),
+            "example_String"
+        );
+    }
+}
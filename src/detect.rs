@@ -0,0 +1,311 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{utils, Case, Options};
+
+/// A case detected in an input, with the share of the input's words and separators that follow it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Detection {
+    /// The detected case.
+    pub case: Case,
+    /// How much of the input follows the case, from `0.0` (exclusive) to `1.0` for an exact match.
+    pub confidence: f64,
+}
+
+/// The casing of a word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WordShape {
+    Lower,
+    Upper,
+    Title,
+    /// A single uppercase letter, both `Upper` and `Title`.
+    Capital,
+    /// A word without cased letters, like `42` or `まつり`, which fits any casing.
+    Uncased,
+    Mixed,
+}
+
+impl WordShape {
+    pub(crate) fn of(word: &str) -> WordShape {
+        let mut cased = word
+            .graphemes(true)
+            .filter(|g| utils::is_capital(g) || utils::is_lowercase(g));
+
+        let Some(first) = cased.next() else {
+            return WordShape::Uncased;
+        };
+        let rest: Vec<&str> = cased.collect();
+        let rest_lower = rest.iter().all(|g| utils::is_lowercase(g));
+
+        match (utils::is_capital(first), rest.is_empty()) {
+            (true, true) => WordShape::Capital,
+            (true, false) if rest_lower => WordShape::Title,
+            (true, false) if rest.iter().all(|g| utils::is_capital(g)) => WordShape::Upper,
+            (false, _) if rest_lower => WordShape::Lower,
+            _ => WordShape::Mixed,
+        }
+    }
+
+    fn fits(self, expected: WordShape, allow_acronyms: bool) -> bool {
+        match (self, expected) {
+            (WordShape::Uncased, _) => true,
+            (WordShape::Capital, WordShape::Upper | WordShape::Title) => true,
+            (WordShape::Upper, WordShape::Title) => allow_acronyms,
+            _ => self == expected,
+        }
+    }
+}
+
+/// The separator and the casing of the first and other words of a case.
+pub(crate) struct Shape {
+    pub(crate) separator: &'static str,
+    pub(crate) first_word: WordShape,
+    pub(crate) other_words: WordShape,
+}
+
+impl Case {
+    /// Returns the separator and word casings of the case, or None for `AlTeRnAtInG cAsE`.
+    pub(crate) fn shape(self) -> Option<Shape> {
+        use WordShape::*;
+
+        let (separator, first_word, other_words) = match self {
+            Case::Normal => (" ", Lower, Lower),
+            Case::Camel => ("", Lower, Title),
+            Case::Pascal => ("", Title, Title),
+            Case::Snake => ("_", Lower, Lower),
+            Case::Kebab => ("-", Lower, Lower),
+            Case::Dot => (".", Lower, Lower),
+            Case::Path => ("/", Lower, Lower),
+            Case::WindowsPath => ("\\", Lower, Lower),
+            Case::Sentence => (" ", Title, Lower),
+            Case::Title => (" ", Title, Title),
+            Case::Header => ("-", Title, Title),
+            Case::UpperSnake => ("_", Upper, Upper),
+            Case::Flat => ("", Lower, Lower),
+            Case::UpperFlat => ("", Upper, Upper),
+            Case::Cobol => ("-", Upper, Upper),
+            Case::UpperDot => (".", Upper, Upper),
+            Case::Ada => ("_", Title, Title),
+            Case::CamelSnake => ("_", Lower, Title),
+            Case::Alternating => return None,
+        };
+        Some(Shape {
+            separator,
+            first_word,
+            other_words,
+        })
+    }
+
+    /// Returns the cases the input is written in. Several cases can match, e.g. `foo` is both `snake_case` and
+    /// `kebab-case`. Runs of capitals are accepted as words of `camelCase` and `PascalCase` inputs, so
+    /// `parseJSON` is `camelCase`.
+    /// ## Example
+    /// ```
+    /// use recase::Case;
+    ///
+    /// assert_eq!(Case::detect("fooBar"), vec![Case::Camel]);
+    /// assert_eq!(Case::detect("Foo-Bar"), vec![Case::Header]);
+    /// assert!(Case::detect("foo").contains(&Case::Snake));
+    /// assert!(Case::detect("foo").contains(&Case::Kebab));
+    /// assert_eq!(Case::detect("foo_Bar-baz"), vec![]);
+    /// ```
+    pub fn detect(input: &str) -> Vec<Case> {
+        Case::detect_ranked(input)
+            .into_iter()
+            .filter(|detection| detection.confidence == 1.0)
+            .map(|detection| detection.case)
+            .collect()
+    }
+
+    /// Returns every case the input partially follows, ranked by confidence, from the most likely.
+    /// The confidence of a case is the share of the input's words and separators that follow it.
+    /// ## Example
+    /// ```
+    /// use recase::Case;
+    ///
+    /// let ranked = Case::detect_ranked("foo_bar_baz-qux");
+    /// assert_eq!(ranked[0].case, recase::Case::Snake);
+    /// assert!(ranked[0].confidence > 0.8 && ranked[0].confidence < 1.0);
+    /// ```
+    pub fn detect_ranked(input: &str) -> Vec<Detection> {
+        let options = Options::detection();
+        let spans = utils::slice_into_spans(input, &options);
+        if spans.is_empty() {
+            return vec![];
+        }
+
+        let shapes: Vec<WordShape> = spans
+            .iter()
+            .map(|span| WordShape::of(&input[span.clone()]))
+            .collect();
+        let gaps: Vec<&str> = spans
+            .windows(2)
+            .map(|pair| &input[pair[0].end..pair[1].start])
+            .collect();
+        // leading and trailing separators don't belong to any case
+        let affixes = [
+            &input[..spans[0].start],
+            &input[spans[spans.len() - 1].end..],
+        ]
+        .iter()
+        .filter(|affix| !affix.is_empty())
+        .count();
+
+        let mut detections: Vec<Detection> = Case::all()
+            .map(|case| {
+                let confidence = match case.shape() {
+                    Some(shape) => shape_confidence(&shape, case, &shapes, &gaps, affixes),
+                    None => alternating_confidence(input),
+                };
+                Detection { case, confidence }
+            })
+            .filter(|detection| detection.confidence > 0.0)
+            .collect();
+
+        detections.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        detections
+    }
+}
+
+fn shape_confidence(
+    shape: &Shape,
+    case: Case,
+    words: &[WordShape],
+    gaps: &[&str],
+    affixes: usize,
+) -> f64 {
+    let allow_acronyms = matches!(case, Case::Camel | Case::Pascal);
+
+    let fitting_words = words
+        .iter()
+        .enumerate()
+        .filter(|&(i, word)| {
+            let expected = if i == 0 {
+                shape.first_word
+            } else {
+                shape.other_words
+            };
+            word.fits(expected, allow_acronyms)
+        })
+        .count();
+    let fitting_gaps = gaps.iter().filter(|&&gap| gap == shape.separator).count();
+
+    (fitting_words + fitting_gaps) as f64 / (words.len() + gaps.len() + affixes) as f64
+}
+
+/// Returns the share of cased letters that alternate as `AlTeRnAtInG cAsE` does, if words are separated by spaces
+/// and the input has an uppercase letter.
+fn alternating_confidence(input: &str) -> f64 {
+    let mut uppercase = true;
+    let mut cased = 0;
+    let mut fitting = 0;
+    let mut has_uppercase = false;
+
+    for g in input.graphemes(true) {
+        if g == " " {
+            continue;
+        }
+        uppercase = !uppercase;
+        if utils::is_capital(g) || utils::is_lowercase(g) {
+            cased += 1;
+            has_uppercase |= utils::is_capital(g);
+            if utils::is_capital(g) == uppercase {
+                fitting += 1;
+            }
+        }
+    }
+
+    if !has_uppercase || cased < 2 {
+        return 0.0;
+    }
+    fitting as f64 / cased as f64
+}
+
+#[cfg(test)]
+mod detect_tests {
+    use crate::detect::WordShape;
+    use crate::{Case, ReCase};
+
+    #[test]
+    fn test_word_shapes() {
+        assert_eq!(WordShape::of("foo"), WordShape::Lower);
+        assert_eq!(WordShape::of("Foo"), WordShape::Title);
+        assert_eq!(WordShape::of("FOO"), WordShape::Upper);
+        assert_eq!(WordShape::of("A"), WordShape::Capital);
+        assert_eq!(WordShape::of("fOO"), WordShape::Mixed);
+        assert_eq!(WordShape::of("42"), WordShape::Uncased);
+        assert_eq!(WordShape::of("Äß"), WordShape::Title);
+        assert_eq!(WordShape::of("ǅungla"), WordShape::Title);
+        assert_eq!(WordShape::of("ǆungla"), WordShape::Lower);
+        assert_eq!(WordShape::of("ǅ"), WordShape::Capital);
+    }
+
+    #[test]
+    fn test_detect_exact() {
+        let input = [
+            ("fooBar", vec![Case::Camel]),
+            ("parseJSONResponse", vec![Case::Camel]),
+            ("HTTPServer", vec![Case::Pascal]),
+            ("foo_bar", vec![Case::Snake]),
+            ("FOO_BAR", vec![Case::UpperSnake]),
+            ("Foo-Bar", vec![Case::Header]),
+            ("Foo bar", vec![Case::Sentence]),
+            ("Foo Bar", vec![Case::Title]),
+            ("fOo BaR", vec![Case::Alternating]),
+            ("ĞodMatsuri", vec![Case::Pascal]),
+            ("göd.mätsuri", vec![Case::Dot]),
+            ("FOO-BAR", vec![Case::Cobol]),
+            ("Foo_Bar", vec![Case::Ada]),
+            ("foo_Bar", vec![Case::CamelSnake]),
+            (
+                "foo",
+                vec![
+                    Case::Camel,
+                    Case::Snake,
+                    Case::Kebab,
+                    Case::Dot,
+                    Case::Path,
+                    Case::WindowsPath,
+                    Case::Normal,
+                    Case::Flat,
+                    Case::CamelSnake,
+                ],
+            ),
+            ("_foo_bar", vec![]),
+            ("", vec![]),
+        ];
+
+        for (s, expected) in input {
+            assert_eq!(Case::detect(s), expected, "{s}");
+        }
+
+        // titlecase digraphs are capitals
+        assert_eq!(Case::detect("ǅungla"), Case::detect("Dungla"));
+        assert_eq!(Case::detect("ǅunglaǈubav"), vec![Case::Pascal]);
+    }
+
+    #[test]
+    fn test_detect_every_case() {
+        let recase = ReCase::new("detected example text");
+        for case in Case::all() {
+            assert!(
+                Case::detect(&recase.to_case(case)).contains(&case),
+                "{case}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_detect_ranked() {
+        let ranked = Case::detect_ranked("foo_bar-baz");
+        assert_eq!(ranked[0].case, Case::Snake);
+        assert_eq!(ranked[1].case, Case::Kebab);
+        assert_eq!(ranked[0].confidence, 0.8);
+
+        let ranked = Case::detect_ranked("fooBar_baz");
+        assert_eq!(ranked[0].case, Case::Camel);
+
+        let ranked = Case::detect_ranked("_foo_bar_");
+        assert_eq!(ranked[0].case, Case::Snake);
+        assert_eq!(ranked[0].confidence, 0.6);
+    }
+}
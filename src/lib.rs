@@ -2,9 +2,42 @@
 //!
 //! `recase` is a text processing utility that changes the input text into desired convention cases.
 
+use std::borrow::Cow;
+use std::ops::Range;
+
 use unicode_segmentation::UnicodeSegmentation;
 
+mod acronyms;
+mod case;
+mod custom;
+mod detect;
+mod locale;
+mod naming;
+mod options;
+mod pattern;
+mod round_trip;
+mod sanitize;
+mod segmenter;
+mod title;
 mod utils;
+mod validate;
+
+pub use acronyms::{AcronymStyle, Acronyms};
+pub use case::{Case, ParseCaseError};
+pub use custom::{CaseBuilder, CustomCase, InferCaseError, WordCase};
+pub use detect::Detection;
+pub use locale::Locale;
+pub use naming::ItemKind;
+pub use options::{
+    DigitBoundary, DigitOutput, EdgeSeparators, Normalization, Options, Segmentation, Separators,
+    SharpS, Sigils,
+};
+pub use pattern::{PatternError, PatternErrorKind};
+pub use round_trip::{Loss, RoundTrip};
+pub use sanitize::{KeywordEscape, Language, Sanitizer};
+pub use segmenter::{DefaultSegmenter, Segmenter};
+pub use title::TitleStyle;
+pub use validate::{validate, Validation, Violation, ViolationKind};
 
 /// An instance that holds the text to be re-cased.
 /// # Example
@@ -17,6 +50,10 @@ mod utils;
 pub struct ReCase {
     original_text: String,
     words: Vec<String>,
+    spans: Vec<Range<usize>>,
+    /// The byte range of the text between its sigils.
+    body: Range<usize>,
+    options: Options,
 }
 
 impl ReCase {
@@ -24,14 +61,68 @@ impl ReCase {
     /// supported convention cases.
     /// The method takes a &str or a String as an input.
     pub fn new<S: Into<String>>(original_text: S) -> ReCase {
-        let original_text = original_text.into();
-        let words = utils::slice_into_words(original_text.clone());
+        ReCase::with_options(original_text, Options::default())
+    }
+
+    /// Create a new ReCase instance that splits the input text into words according to the given options.
+    /// ## Example
+    /// ```
+    /// use recase::{Options, ReCase, Segmentation};
+    ///
+    /// let options = Options::new().segmentation(Segmentation::Acronym);
+    /// let recase = ReCase::with_options("HTTPServer", options);
+    /// assert_eq!(recase.snake_case(), String::from("http_server"));
+    /// ```
+    pub fn with_options<S: Into<String>>(original_text: S, options: Options) -> ReCase {
+        let original_text = match options.normalization {
+            Some(normalization) => normalization.normalize(&original_text.into()),
+            None => original_text.into(),
+        };
+        let body = match &options.sigils {
+            Some(sigils) => sigils.body(&original_text),
+            None => 0..original_text.len(),
+        };
+        let spans: Vec<Range<usize>> = utils::segment(&original_text[body.clone()], &options)
+            .into_iter()
+            .map(|span| span.start + body.start..span.end + body.start)
+            .collect();
+        let words = spans
+            .iter()
+            .map(|span| {
+                let word = &original_text[span.clone()];
+                match options.locale {
+                    Some(locale) => locale.to_lowercase(word),
+                    None => utils::lowercase(word),
+                }
+            })
+            .collect();
         ReCase {
             original_text,
             words,
+            spans,
+            body,
+            options,
         }
     }
 
+    /// Create a new ReCase instance that splits the input text into words with the given segmenter.
+    /// ## Example
+    /// ```
+    /// use recase::ReCase;
+    ///
+    /// // every grapheme is a word
+    /// let segmenter = |input: &str| input.char_indices().map(|(i, c)| i..i + c.len_utf8()).collect();
+    /// let recase = ReCase::with_segmenter("abc", segmenter);
+    /// assert_eq!(recase.kebab_case(), String::from("a-b-c"));
+    /// ```
+    pub fn with_segmenter<S, T>(original_text: S, segmenter: T) -> ReCase
+    where
+        S: Into<String>,
+        T: Segmenter + 'static,
+    {
+        ReCase::with_options(original_text, Options::new().segmenter(segmenter))
+    }
+
     /// Create a new ReCase instance. Once created, it can be used repeatedly to convert the input text into
     /// supported convention cases.
     /// The method takes a &str as an input.
@@ -40,7 +131,7 @@ impl ReCase {
         ReCase::new(original_text.to_string())
     }
 
-    /// Returns a clone of the original String
+    /// Returns a clone of the original String, in the normalization form of the options if they have one
     /// ## Example
     /// ```
     /// let recase = recase::ReCase::new(String::from("Example String"));
@@ -57,7 +148,7 @@ impl ReCase {
     /// assert_eq!(recase.normal_case(), String::from("example string"));
     /// ```
     pub fn normal_case(&self) -> String {
-        self.words.join(" ")
+        self.join_words(" ", |res, _, word| res.push_str(word))
     }
 
     /// Returns a `camelCase` version of the input text as a new String
@@ -67,16 +158,13 @@ impl ReCase {
     /// assert_eq!(recase.camel_case(), String::from("exampleString"));
     /// ```
     pub fn camel_case(&self) -> String {
-        match self.words.split_first() {
-            None => "".to_owned(),
-            Some((first_word, the_rest)) => the_rest
-                .iter()
-                .map(|s| utils::uppercase_first_letter(s))
-                .fold(first_word.to_owned(), |mut acc, s| {
-                    acc.push_str(&s);
-                    acc
-                }),
-        }
+        self.join_words("", |res, i, word| {
+            if i == 0 {
+                res.push_str(word);
+            } else {
+                self.push_capitalized(res, i, word);
+            }
+        })
     }
 
     /// Returns a `PascalCase` version of the input text as a new String
@@ -86,16 +174,7 @@ impl ReCase {
     /// assert_eq!(recase.pascal_case(), String::from("ExampleString"));
     /// ```
     pub fn pascal_case(&self) -> String {
-        match self.words.split_first() {
-            None => "".to_owned(),
-            Some((first_word, the_rest)) => the_rest
-                .iter()
-                .map(|s| utils::uppercase_first_letter(s))
-                .fold(utils::uppercase_first_letter(first_word), |mut acc, s| {
-                    acc.push_str(&s);
-                    acc
-                }),
-        }
+        self.join_words("", |res, i, word| self.push_capitalized(res, i, word))
     }
 
     /// Returns a `snake_case` version of the input text as a new String
@@ -105,7 +184,7 @@ impl ReCase {
     /// assert_eq!(recase.snake_case(), String::from("example_string"));
     /// ```
     pub fn snake_case(&self) -> String {
-        self.words.join("_")
+        self.join_snake_words(|res, _, word| res.push_str(word))
     }
 
     /// Returns a `kebab-case` version of the input text as a new String
@@ -115,7 +194,7 @@ impl ReCase {
     /// assert_eq!(recase.kebab_case(), String::from("example-string"));
     /// ```
     pub fn kebab_case(&self) -> String {
-        self.words.join("-")
+        self.join_words("-", |res, _, word| res.push_str(word))
     }
 
     /// Returns a `dot.case` version of the input text as a new String
@@ -125,7 +204,7 @@ impl ReCase {
     /// assert_eq!(recase.dot_case(), String::from("example.string"));
     /// ```
     pub fn dot_case(&self) -> String {
-        self.words.join(".")
+        self.join_words(".", |res, _, word| res.push_str(word))
     }
 
     /// Returns a `path/case` version of the input text as a new String
@@ -135,7 +214,7 @@ impl ReCase {
     /// assert_eq!(recase.path_case(), String::from("example/string"));
     /// ```
     pub fn path_case(&self) -> String {
-        self.words.join("/")
+        self.join_words("/", |res, _, word| res.push_str(word))
     }
 
     /// Returns a `windows\path\case` version of the input text as a new String
@@ -145,7 +224,7 @@ impl ReCase {
     /// assert_eq!(recase.windows_path_case(), String::from("example\\string"));
     /// ```
     pub fn windows_path_case(&self) -> String {
-        self.words.join("\\")
+        self.join_words("\\", |res, _, word| res.push_str(word))
     }
 
     /// Returns a `Sentence case` version of the input text as a new String
@@ -155,17 +234,13 @@ impl ReCase {
     /// assert_eq!(recase.sentence_case(), String::from("Example string"));
     /// ```
     pub fn sentence_case(&self) -> String {
-        match self.words.split_first() {
-            None => "".to_owned(),
-            Some((first_word, the_rest)) => {
-                let mut res = utils::uppercase_first_letter(first_word);
-                for word in the_rest {
-                    res.push_str(" ");
-                    res.push_str(word);
-                }
-                res
+        self.join_words(" ", |res, i, word| {
+            if i == 0 {
+                self.push_capitalized(res, i, word);
+            } else {
+                self.push_acronym_or_word(res, i, word);
             }
-        }
+        })
     }
 
     /// Returns a `Title Case` version of the input text as a new String
@@ -175,11 +250,75 @@ impl ReCase {
     /// assert_eq!(recase.title_case(), String::from("Example String"));
     /// ```
     pub fn title_case(&self) -> String {
-        self.words
-            .iter()
-            .map(|w| utils::uppercase_first_letter(&w))
-            .collect::<Vec<String>>()
-            .join(" ")
+        self.join_words(" ", |res, i, word| self.push_capitalized(res, i, word))
+    }
+
+    /// Returns a `Title Case` version of the input text following a style guide as a new String. Minor words stay
+    /// lowercase unless they start or end the title or follow a colon, and words joined by a hyphen in the input
+    /// stay hyphenated.
+    /// ## Example
+    /// ```
+    /// use recase::{ReCase, TitleStyle};
+    /// let recase = ReCase::new(String::from("the lord of the rings"));
+    /// assert_eq!(recase.title_case_with(TitleStyle::Chicago), String::from("The Lord of the Rings"));
+    /// ```
+    pub fn title_case_with(&self, style: TitleStyle) -> String {
+        let last = self.words.len().saturating_sub(1);
+
+        self.join_words(" ", |res, i, word| {
+            let hyphenated = i > 0 && self.gap_before(i) == "-";
+            if hyphenated && res.ends_with(' ') {
+                res.pop();
+                res.push('-');
+            }
+
+            let after_colon = i > 0 && self.words[i - 1].ends_with(':');
+            let minor = i != 0 && i != last && !after_colon && style.is_minor_word(word);
+            if minor || (hyphenated && style.lowercases_after(&self.words[i - 1])) {
+                self.push_acronym_or_word(res, i, word);
+            } else {
+                self.push_capitalized(res, i, word);
+            }
+        })
+    }
+
+    /// Returns a `Title Case` version of the input text following the conventions of a language as a new String.
+    /// Minor words of the language stay lowercase unless they start the title or follow a colon, and German titles
+    /// are written in `Sentence case`. English, Turkish, Azerbaijani, Lithuanian and Greek have no minor words, so
+    /// every word of their titles is capitalized.
+    /// ## Example
+    /// ```
+    /// use recase::{Locale, ReCase};
+    /// let recase = ReCase::new(String::from("cien años de soledad"));
+    /// assert_eq!(recase.title_case_locale(Locale::Es), String::from("Cien Años de Soledad"));
+    /// ```
+    pub fn title_case_locale(&self, locale: Locale) -> String {
+        match locale {
+            Locale::En => return self.title_case(),
+            Locale::De => return self.sentence_case(),
+            _ => {}
+        }
+
+        self.join_words(" ", |res, i, word| {
+            let after_colon = i > 0 && self.words[i - 1].ends_with(':');
+            let capitalized = i == 0 || after_colon;
+
+            if !capitalized && locale.is_minor_word(word) {
+                self.push_acronym_or_word(res, i, word);
+            } else if let Some((elision, rest)) = locale.split_elision(word) {
+                if capitalized {
+                    res.push_str(&self.capitalize_in(Some(locale), elision));
+                } else {
+                    res.push_str(elision);
+                }
+                res.push_str(&self.capitalize_in(Some(locale), rest));
+            } else {
+                match self.acronym_spelling(i, word) {
+                    Some(acronym) => res.push_str(&acronym),
+                    None => res.push_str(&self.capitalize_in(Some(locale), word)),
+                }
+            }
+        })
     }
 
     /// Returns a `Header-Case` version of the input text as a new String
@@ -189,11 +328,7 @@ impl ReCase {
     /// assert_eq!(recase.header_case(), String::from("Example-String"));
     /// ```
     pub fn header_case(&self) -> String {
-        self.words
-            .iter()
-            .map(|w| utils::uppercase_first_letter(&w))
-            .collect::<Vec<String>>()
-            .join("-")
+        self.join_words("-", |res, i, word| self.push_capitalized(res, i, word))
     }
 
     /// Returns a `UPPER_SNAKE_CASE` version of the input text as a new String
@@ -203,11 +338,94 @@ impl ReCase {
     /// assert_eq!(recase.upper_snake_case(), String::from("EXAMPLE_STRING"));
     /// ```
     pub fn upper_snake_case(&self) -> String {
-        self.words
-            .iter()
-            .map(|w| w.to_uppercase())
-            .collect::<Vec<String>>()
-            .join("_")
+        self.join_snake_words(|res, _, word| res.push_str(&self.uppercase(word)))
+    }
+
+    /// Returns a `flatcase` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// let recase = recase::ReCase::new(String::from("Example String"));
+    /// assert_eq!(recase.flat_case(), String::from("examplestring"));
+    /// ```
+    pub fn flat_case(&self) -> String {
+        self.join_words("", |res, _, word| res.push_str(word))
+    }
+
+    /// Returns a `UPPERFLATCASE` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// let recase = recase::ReCase::new(String::from("Example String"));
+    /// assert_eq!(recase.upper_flat_case(), String::from("EXAMPLESTRING"));
+    /// ```
+    pub fn upper_flat_case(&self) -> String {
+        self.join_words("", |res, _, word| res.push_str(&self.uppercase(word)))
+    }
+
+    /// Returns a `COBOL-CASE` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// let recase = recase::ReCase::new(String::from("Example String"));
+    /// assert_eq!(recase.cobol_case(), String::from("EXAMPLE-STRING"));
+    /// ```
+    pub fn cobol_case(&self) -> String {
+        self.join_words("-", |res, _, word| res.push_str(&self.uppercase(word)))
+    }
+
+    /// Returns a `UPPER.DOT.CASE` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// let recase = recase::ReCase::new(String::from("Example String"));
+    /// assert_eq!(recase.upper_dot_case(), String::from("EXAMPLE.STRING"));
+    /// ```
+    pub fn upper_dot_case(&self) -> String {
+        self.join_words(".", |res, _, word| res.push_str(&self.uppercase(word)))
+    }
+
+    /// Returns a `Ada_Case` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// let recase = recase::ReCase::new(String::from("Example String"));
+    /// assert_eq!(recase.ada_case(), String::from("Example_String"));
+    /// ```
+    pub fn ada_case(&self) -> String {
+        self.join_words("_", |res, i, word| self.push_capitalized(res, i, word))
+    }
+
+    /// Returns a `camel_Snake_Case` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// let recase = recase::ReCase::new(String::from("Example String"));
+    /// assert_eq!(recase.camel_snake_case(), String::from("example_String"));
+    /// ```
+    pub fn camel_snake_case(&self) -> String {
+        self.join_words("_", |res, i, word| {
+            if i == 0 {
+                res.push_str(word);
+            } else {
+                self.push_capitalized(res, i, word);
+            }
+        })
+    }
+
+    /// Returns a `lower sentence case` version of the input text as a new String, which is the same as
+    /// `normal case`
+    /// ## Example
+    /// ```
+    /// let recase = recase::ReCase::new(String::from("Example String"));
+    /// assert_eq!(recase.lower_sentence_case(), String::from("example string"));
+    /// ```
+    pub fn lower_sentence_case(&self) -> String {
+        self.normal_case()
+    }
+
+    /// Returns a `Train-Case` version of the input text as a new String, which is the same as `Header-Case`
+    /// ## Example
+    /// ```
+    /// let recase = recase::ReCase::new(String::from("Example String"));
+    /// assert_eq!(recase.train_case(), String::from("Example-String"));
+    /// ```
+    pub fn train_case(&self) -> String {
+        self.header_case()
     }
 
     /// Returns a `AlTeRnAtInG cAsE` version of the input text as a new String
@@ -218,29 +436,320 @@ impl ReCase {
     /// ```
     pub fn alternating_case(&self) -> String {
         let mut uppercase = true;
-        let mut res = String::with_capacity(self.original_text.len());
-
-        for (i, word) in self.words.iter().enumerate() {
-            if i != 0 {
-                res.push_str(" ");
-            }
 
+        self.join_words(" ", |res, _, word| {
             let chars = word.graphemes(true);
             chars.for_each(|c| {
                 uppercase = !uppercase;
                 if uppercase {
-                    res.push_str(&c.to_uppercase());
+                    res.push_str(&self.uppercase(c));
                 } else {
-                    res.push_str(&c.to_lowercase());
+                    res.push_str(&self.lowercase(c));
                 }
             });
+        })
+    }
+
+    /// Returns a version of the input text in the given case as a new String
+    /// ## Example
+    /// ```
+    /// use recase::{Case, ReCase};
+    /// let recase = ReCase::new(String::from("Example String"));
+    /// assert_eq!(recase.to_case(Case::Kebab), String::from("example-string"));
+    /// ```
+    pub fn to_case(&self, case: Case) -> String {
+        match case {
+            Case::Normal => self.normal_case(),
+            Case::Camel => self.camel_case(),
+            Case::Pascal => self.pascal_case(),
+            Case::Snake => self.snake_case(),
+            Case::Kebab => self.kebab_case(),
+            Case::Dot => self.dot_case(),
+            Case::Path => self.path_case(),
+            Case::WindowsPath => self.windows_path_case(),
+            Case::Sentence => self.sentence_case(),
+            Case::Title => self.title_case(),
+            Case::Header => self.header_case(),
+            Case::UpperSnake => self.upper_snake_case(),
+            Case::Flat => self.flat_case(),
+            Case::UpperFlat => self.upper_flat_case(),
+            Case::Cobol => self.cobol_case(),
+            Case::UpperDot => self.upper_dot_case(),
+            Case::Ada => self.ada_case(),
+            Case::CamelSnake => self.camel_snake_case(),
+            Case::Alternating => self.alternating_case(),
+        }
+    }
+
+    /// Converts the input text into the given case and reads the output back with the same options, reporting
+    /// the words that don't come back the same, such as `ßlong` read back as `sslong` from `SSLONG`, or words
+    /// joined by `flatcase`.
+    /// ## Example
+    /// ```
+    /// use recase::{Case, ReCase};
+    /// let recase = ReCase::new(String::from("example string"));
+    /// assert!(recase.round_trip(Case::Kebab).is_lossless());
+    ///
+    /// let round_trip = recase.round_trip(Case::Flat);
+    /// assert_eq!(round_trip.output, String::from("examplestring"));
+    /// assert_eq!(round_trip.losses[0].parsed, vec![String::from("examplestring")]);
+    /// ```
+    pub fn round_trip(&self, case: Case) -> RoundTrip {
+        let output = self.to_case(case);
+        let parsed = ReCase::with_options(output.as_str(), self.options.clone());
+        let losses = round_trip::losses(&self.spans, &self.words, &parsed.words);
+        RoundTrip { output, losses }
+    }
+
+    /// Returns a version of the input text in a case defined with a [`CaseBuilder`] as a new String
+    /// ## Example
+    /// ```
+    /// use recase::{CaseBuilder, ReCase, WordCase};
+    /// let cobol_case = CaseBuilder::new()
+    ///     .separator("-")
+    ///     .first_word(WordCase::Upper)
+    ///     .other_words(WordCase::Upper)
+    ///     .build();
+    /// let recase = ReCase::new(String::from("Example String"));
+    /// assert_eq!(recase.custom_case(&cobol_case), String::from("EXAMPLE-STRING"));
+    /// ```
+    pub fn custom_case(&self, case: &CustomCase) -> String {
+        // like the other outputs, an input without words gives no prefix and suffix
+        let has_words = !self.words.is_empty();
+        let mut res = String::new();
+        if has_words {
+            res.push_str(&case.prefix);
         }
+        self.push_words(&mut res, &case.separator, false, |res, i, word| {
+            let word_case = if i == 0 {
+                case.first_word
+            } else {
+                case.other_words
+            };
+            match word_case {
+                WordCase::Lower => res.push_str(word),
+                WordCase::Upper => res.push_str(&self.uppercase(word)),
+                WordCase::Title => self.push_capitalized(res, i, word),
+            }
+        });
+        if has_words {
+            res.push_str(&case.suffix);
+        }
+        self.finish(res, &case.separator)
+    }
+
+    /// Joins the words into a single buffer, using `push_word` to write each (index, word) pair.
+    fn join_words<F>(&self, separator: &str, push_word: F) -> String
+    where
+        F: FnMut(&mut String, usize, &str),
+    {
+        self.join_words_with(separator, false, push_word)
+    }
+
+    /// Joins the words with underscores, attaching single letters to the next word if asked to.
+    fn join_snake_words<F>(&self, push_word: F) -> String
+    where
+        F: FnMut(&mut String, usize, &str),
+    {
+        self.join_words_with("_", self.options.join_single_letters, push_word)
+    }
+
+    fn join_words_with<F>(&self, separator: &str, join_single_letters: bool, push_word: F) -> String
+    where
+        F: FnMut(&mut String, usize, &str),
+    {
+        let mut res = String::with_capacity(self.original_text.len());
+        self.push_words(&mut res, separator, join_single_letters, push_word);
+        self.finish(res, separator)
+    }
+
+    /// Writes the words and the separators between them at the end of `res`.
+    fn push_words<F>(
+        &self,
+        res: &mut String,
+        separator: &str,
+        join_single_letters: bool,
+        mut push_word: F,
+    ) where
+        F: FnMut(&mut String, usize, &str),
+    {
+        let (leading, trailing) = self.edge_separators();
+        self.push_edge_separators(res, leading, separator);
+
+        for (i, word) in self.words.iter().enumerate() {
+            if i != 0 {
+                let joined = self.attaches_to_previous(word)
+                    || (join_single_letters && utils::is_single_letter(&self.words[i - 1]));
+                if !joined {
+                    res.push_str(separator);
+                }
+                if separator.is_empty() || joined {
+                    self.push_uncased_separator(res, word);
+                }
+            }
+            push_word(res, i, word);
+        }
+
+        self.push_edge_separators(res, trailing, separator);
+    }
+
+    /// Returns the separators before the first word and after the last one.
+    fn edge_separators(&self) -> (&str, &str) {
+        match (self.spans.first(), self.spans.last()) {
+            (Some(first), Some(last)) => (
+                &self.original_text[self.body.start..first.start],
+                &self.original_text[last.end..self.body.end],
+            ),
+            _ => ("", ""),
+        }
+    }
+
+    fn push_edge_separators(&self, res: &mut String, edge: &str, separator: &str) {
+        match self.options.edge_separators {
+            EdgeSeparators::Drop => {}
+            EdgeSeparators::Map if !separator.is_empty() => {
+                edge.graphemes(true).for_each(|_| res.push_str(separator))
+            }
+            EdgeSeparators::Keep | EdgeSeparators::Map => res.push_str(edge),
+        }
+    }
+
+    /// Sanitizes an output, writes the sigils of the text around it and normalizes it.
+    fn finish(&self, res: String, separator: &str) -> String {
+        let mut res = self.sanitize(res, separator);
+        if self.body != (0..self.original_text.len()) {
+            let prefix = &self.original_text[..self.body.start];
+            let suffix = &self.original_text[self.body.end..];
+            let mut with_sigils = String::with_capacity(prefix.len() + res.len() + suffix.len());
+            with_sigils.push_str(prefix);
+            with_sigils.push_str(&res);
+            with_sigils.push_str(suffix);
+            res = with_sigils;
+        }
+
+        // case mappings can leave decomposed characters, like the Lithuanian `i̇̀` uppercased as `I` and a grave accent
+        match self.options.normalization {
+            Some(normalization) => normalization.normalize(&res),
+            None => res,
+        }
+    }
+
+    /// Turns an output into a valid identifier if the options have a sanitizer and the output forms one.
+    fn sanitize(&self, res: String, separator: &str) -> String {
+        match &self.options.sanitizer {
+            Some(sanitizer) if sanitizer.is_identifier(separator) => sanitizer.sanitize(&res),
+            // `dot.case` outputs are qualified names, whose parts are sanitized one by one
+            Some(sanitizer) if separator == "." => res
+                .split(separator)
+                .map(|part| match part {
+                    // kept edge separators
+                    "" => String::new(),
+                    part => sanitizer.sanitize(part),
+                })
+                .collect::<Vec<String>>()
+                .join(separator),
+            // prose, paths and file names like `kebab-case` are not identifiers
+            _ => res,
+        }
+    }
+
+    /// Writes the uncased separator if the words on both sides of it meet on characters without case.
+    fn push_uncased_separator(&self, res: &mut String, next_word: &str) {
+        let Some(separator) = &self.options.uncased_separator else {
+            return;
+        };
+        let is_uncased = |c: char| !c.is_lowercase() && !c.is_uppercase();
+
+        if res.chars().next_back().is_some_and(is_uncased)
+            && next_word.chars().next().is_some_and(is_uncased)
+        {
+            res.push_str(separator);
+        }
+    }
+
+    /// Writes a word with its first letter uppercased, or the spelling of its acronym.
+    fn push_capitalized(&self, res: &mut String, i: usize, word: &str) {
+        match self.acronym_spelling(i, word) {
+            Some(acronym) => res.push_str(&acronym),
+            None => res.push_str(&self.capitalize(word)),
+        }
+    }
+
+    /// Writes a word as is, or the spelling of its acronym.
+    fn push_acronym_or_word(&self, res: &mut String, i: usize, word: &str) {
+        match self.acronym_spelling(i, word) {
+            Some(acronym) => res.push_str(&acronym),
+            None => res.push_str(word),
+        }
+    }
+
+    fn acronym_spelling(&self, i: usize, word: &str) -> Option<Cow<'_, str>> {
+        let canonical = || self.options.acronyms.get(word).map(Cow::Borrowed);
+
+        match self.options.acronym_style {
+            AcronymStyle::Flattened => None,
+            AcronymStyle::Canonical => canonical(),
+            AcronymStyle::TwoLetterUpper => canonical().or_else(|| {
+                let original = &self.original_text[self.spans[i].clone()];
+                (original.graphemes(true).count() == 2 && utils::is_all_uppercase(original))
+                    .then(|| Cow::Owned(self.uppercase(word)))
+            }),
+        }
+    }
+
+    fn lowercase(&self, text: &str) -> String {
+        match self.options.locale {
+            Some(locale) => locale.to_lowercase(text),
+            None => utils::lowercase(text),
+        }
+    }
+
+    fn uppercase(&self, text: &str) -> String {
+        match self.options.locale {
+            Some(locale) => locale.to_uppercase(text),
+            None => text.to_uppercase(),
+        }
+    }
 
-        res
+    fn capitalize(&self, word: &str) -> String {
+        self.capitalize_in(self.options.locale, word)
+    }
+
+    /// Capitalizes a word with the titlecase mapping of a locale, or the Unicode one.
+    fn capitalize_in(&self, locale: Option<Locale>, word: &str) -> String {
+        if self.options.sharp_s == SharpS::Capital {
+            if let Some(rest) = word.strip_prefix('ß') {
+                return format!("ẞ{rest}");
+            }
+        }
+
+        match locale {
+            Some(locale) => locale.capitalize(word),
+            None => utils::titlecase_first_letter(word),
+        }
+    }
+
+    /// Returns the text of the input between a word and the previous one.
+    fn gap_before(&self, i: usize) -> &str {
+        &self.original_text[self.spans[i - 1].end..self.spans[i].start]
+    }
+
+    fn attaches_to_previous(&self, word: &str) -> bool {
+        self.options.digit_output == DigitOutput::Attached
+            && word.graphemes(true).next().is_some_and(utils::is_digit)
     }
 }
 
 pub trait Casing {
+    /// Returns a `ReCase` instance of the input text that splits words according to the given options
+    /// ## Example
+    /// ```
+    /// use recase::{Casing, Options, Segmentation};
+    /// let options = Options::new().segmentation(Segmentation::Acronym);
+    /// assert_eq!("IOError".recase_with(options).kebab_case(), String::from("io-error"));
+    /// ```
+    fn recase_with(&self, options: Options) -> ReCase;
+
     /// Returns a `normal case` version of the input text as a new String
     /// ## Example
     /// ```
@@ -321,6 +830,22 @@ pub trait Casing {
     /// ```
     fn to_title_case(&self) -> String;
 
+    /// Returns a `Title Case` version of the input text following a style guide as a new String
+    /// ## Example
+    /// ```
+    /// use recase::{Casing, TitleStyle};
+    /// assert_eq!("the lord of the rings".to_title_case_with(TitleStyle::Ap), String::from("The Lord of the Rings"));
+    /// ```
+    fn to_title_case_with(&self, style: TitleStyle) -> String;
+
+    /// Returns a `Title Case` version of the input text following the conventions of a language as a new String
+    /// ## Example
+    /// ```
+    /// use recase::{Casing, Locale};
+    /// assert_eq!("guerre et paix".to_title_case_locale(Locale::Fr), String::from("Guerre et Paix"));
+    /// ```
+    fn to_title_case_locale(&self, locale: Locale) -> String;
+
     /// Returns a `Header-Case` version of the input text as a new String
     /// ## Example
     /// ```
@@ -337,6 +862,70 @@ pub trait Casing {
     /// ```
     fn to_upper_snake_case(&self) -> String;
 
+    /// Returns a `flatcase` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert_eq!("Example String".to_flat_case(), String::from("examplestring"));
+    /// ```
+    fn to_flat_case(&self) -> String;
+
+    /// Returns a `UPPERFLATCASE` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert_eq!("Example String".to_upper_flat_case(), String::from("EXAMPLESTRING"));
+    /// ```
+    fn to_upper_flat_case(&self) -> String;
+
+    /// Returns a `COBOL-CASE` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert_eq!("Example String".to_cobol_case(), String::from("EXAMPLE-STRING"));
+    /// ```
+    fn to_cobol_case(&self) -> String;
+
+    /// Returns a `UPPER.DOT.CASE` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert_eq!("Example String".to_upper_dot_case(), String::from("EXAMPLE.STRING"));
+    /// ```
+    fn to_upper_dot_case(&self) -> String;
+
+    /// Returns a `Ada_Case` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert_eq!("Example String".to_ada_case(), String::from("Example_String"));
+    /// ```
+    fn to_ada_case(&self) -> String;
+
+    /// Returns a `camel_Snake_Case` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert_eq!("Example String".to_camel_snake_case(), String::from("example_String"));
+    /// ```
+    fn to_camel_snake_case(&self) -> String;
+
+    /// Returns a `lower sentence case` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert_eq!("Example String".to_lower_sentence_case(), String::from("example string"));
+    /// ```
+    fn to_lower_sentence_case(&self) -> String;
+
+    /// Returns a `Train-Case` version of the input text as a new String
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert_eq!("Example String".to_train_case(), String::from("Example-String"));
+    /// ```
+    fn to_train_case(&self) -> String;
+
     /// Returns a `AlTeRnAtInG cAsE` version of the input text as a new String
     /// ## Example
     /// ```
@@ -344,9 +933,211 @@ pub trait Casing {
     /// assert_eq!("Example String".to_alternating_case(), String::from("eXaMpLe StRiNg"));
     /// ```
     fn to_alternating_case(&self) -> String;
+
+    /// Returns a version of the input text in the given case as a new String
+    /// ## Example
+    /// ```
+    /// use recase::{Case, Casing};
+    /// let case: Case = "kebab".parse().unwrap();
+    /// assert_eq!("Example String".to_case(case), String::from("example-string"));
+    /// ```
+    fn to_case(&self, case: Case) -> String;
+
+    /// Returns a version of the input text in a case defined with a [`CaseBuilder`] as a new String
+    /// ## Example
+    /// ```
+    /// use recase::{CaseBuilder, Casing, WordCase};
+    /// let ada_case = CaseBuilder::new().first_word(WordCase::Title).other_words(WordCase::Title).build();
+    /// assert_eq!("Example String".to_custom_case(&ada_case), String::from("Example_String"));
+    /// ```
+    fn to_custom_case(&self, case: &CustomCase) -> String;
+
+    /// Returns true if the input text is written in `normal case`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("example string".is_normal_case());
+    /// assert!(!"Example String".is_normal_case());
+    /// ```
+    fn is_normal_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `camelCase`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("exampleString".is_camel_case());
+    /// assert!(!"example_string".is_camel_case());
+    /// ```
+    fn is_camel_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `PascalCase`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("ExampleString".is_pascal_case());
+    /// assert!(!"exampleString".is_pascal_case());
+    /// ```
+    fn is_pascal_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `snake_case`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("example_string".is_snake_case());
+    /// assert!(!"exampleString".is_snake_case());
+    /// ```
+    fn is_snake_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `kebab-case`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("example-string".is_kebab_case());
+    /// assert!(!"example_string".is_kebab_case());
+    /// ```
+    fn is_kebab_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `dot.case`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("example.string".is_dot_case());
+    /// assert!(!"example-string".is_dot_case());
+    /// ```
+    fn is_dot_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `path/case`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("example/string".is_path_case());
+    /// assert!(!"example\\string".is_path_case());
+    /// ```
+    fn is_path_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `windows\path\case`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("example\\string".is_windows_path_case());
+    /// assert!(!"example/string".is_windows_path_case());
+    /// ```
+    fn is_windows_path_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `Sentence case`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("Example string".is_sentence_case());
+    /// assert!(!"Example String".is_sentence_case());
+    /// ```
+    fn is_sentence_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `Title Case`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("Example String".is_title_case());
+    /// assert!(!"Example string".is_title_case());
+    /// ```
+    fn is_title_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `Header-Case`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("Example-String".is_header_case());
+    /// assert!(!"Example_String".is_header_case());
+    /// ```
+    fn is_header_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `UPPER_SNAKE_CASE`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("EXAMPLE_STRING".is_upper_snake_case());
+    /// assert!(!"EXAMPLE-STRING".is_upper_snake_case());
+    /// ```
+    fn is_upper_snake_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `flatcase`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("examplestring".is_flat_case());
+    /// assert!(!"example_string".is_flat_case());
+    /// ```
+    fn is_flat_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `UPPERFLATCASE`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("EXAMPLESTRING".is_upper_flat_case());
+    /// assert!(!"EXAMPLE_STRING".is_upper_flat_case());
+    /// ```
+    fn is_upper_flat_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `COBOL-CASE`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("EXAMPLE-STRING".is_cobol_case());
+    /// assert!(!"EXAMPLE_STRING".is_cobol_case());
+    /// ```
+    fn is_cobol_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `UPPER.DOT.CASE`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("EXAMPLE.STRING".is_upper_dot_case());
+    /// assert!(!"example.string".is_upper_dot_case());
+    /// ```
+    fn is_upper_dot_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `Ada_Case`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("Example_String".is_ada_case());
+    /// assert!(!"Example_string".is_ada_case());
+    /// ```
+    fn is_ada_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `camel_Snake_Case`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("example_String".is_camel_snake_case());
+    /// assert!(!"exampleString".is_camel_snake_case());
+    /// ```
+    fn is_camel_snake_case(&self) -> bool;
+
+    /// Returns true if the input text is written in `AlTeRnAtInG cAsE`
+    /// ## Example
+    /// ```
+    /// use recase::Casing;
+    /// assert!("eXaMpLe StRiNg".is_alternating_case());
+    /// assert!(!"ExAmPlE sTrInG".is_alternating_case());
+    /// ```
+    fn is_alternating_case(&self) -> bool;
+
+    /// Returns true if the input text is written in the given case, see [`validate()`] for the violations
+    /// ## Example
+    /// ```
+    /// use recase::{Case, Casing};
+    /// assert!("example-string".is_case(Case::Kebab));
+    /// assert!(!"example-String".is_case(Case::Kebab));
+    /// ```
+    fn is_case(&self, case: Case) -> bool;
 }
 
 impl Casing for str {
+    fn recase_with(&self, options: Options) -> ReCase {
+        ReCase::with_options(self, options)
+    }
+
     fn to_normal_case(&self) -> String {
         ReCase::new(self).normal_case()
     }
@@ -387,6 +1178,14 @@ impl Casing for str {
         ReCase::new(self).title_case()
     }
 
+    fn to_title_case_with(&self, style: TitleStyle) -> String {
+        ReCase::new(self).title_case_with(style)
+    }
+
+    fn to_title_case_locale(&self, locale: Locale) -> String {
+        ReCase::new(self).title_case_locale(locale)
+    }
+
     fn to_header_case(&self) -> String {
         ReCase::new(self).header_case()
     }
@@ -395,14 +1194,137 @@ impl Casing for str {
         ReCase::new(self).upper_snake_case()
     }
 
+    fn to_flat_case(&self) -> String {
+        ReCase::new(self).flat_case()
+    }
+
+    fn to_upper_flat_case(&self) -> String {
+        ReCase::new(self).upper_flat_case()
+    }
+
+    fn to_cobol_case(&self) -> String {
+        ReCase::new(self).cobol_case()
+    }
+
+    fn to_upper_dot_case(&self) -> String {
+        ReCase::new(self).upper_dot_case()
+    }
+
+    fn to_ada_case(&self) -> String {
+        ReCase::new(self).ada_case()
+    }
+
+    fn to_camel_snake_case(&self) -> String {
+        ReCase::new(self).camel_snake_case()
+    }
+
+    fn to_lower_sentence_case(&self) -> String {
+        ReCase::new(self).lower_sentence_case()
+    }
+
+    fn to_train_case(&self) -> String {
+        ReCase::new(self).train_case()
+    }
+
     fn to_alternating_case(&self) -> String {
         ReCase::new(self).alternating_case()
     }
+
+    fn to_case(&self, case: Case) -> String {
+        ReCase::new(self).to_case(case)
+    }
+
+    fn to_custom_case(&self, case: &CustomCase) -> String {
+        ReCase::new(self).custom_case(case)
+    }
+
+    fn is_normal_case(&self) -> bool {
+        self.is_case(Case::Normal)
+    }
+
+    fn is_camel_case(&self) -> bool {
+        self.is_case(Case::Camel)
+    }
+
+    fn is_pascal_case(&self) -> bool {
+        self.is_case(Case::Pascal)
+    }
+
+    fn is_snake_case(&self) -> bool {
+        self.is_case(Case::Snake)
+    }
+
+    fn is_kebab_case(&self) -> bool {
+        self.is_case(Case::Kebab)
+    }
+
+    fn is_dot_case(&self) -> bool {
+        self.is_case(Case::Dot)
+    }
+
+    fn is_path_case(&self) -> bool {
+        self.is_case(Case::Path)
+    }
+
+    fn is_windows_path_case(&self) -> bool {
+        self.is_case(Case::WindowsPath)
+    }
+
+    fn is_sentence_case(&self) -> bool {
+        self.is_case(Case::Sentence)
+    }
+
+    fn is_title_case(&self) -> bool {
+        self.is_case(Case::Title)
+    }
+
+    fn is_header_case(&self) -> bool {
+        self.is_case(Case::Header)
+    }
+
+    fn is_upper_snake_case(&self) -> bool {
+        self.is_case(Case::UpperSnake)
+    }
+
+    fn is_flat_case(&self) -> bool {
+        self.is_case(Case::Flat)
+    }
+
+    fn is_upper_flat_case(&self) -> bool {
+        self.is_case(Case::UpperFlat)
+    }
+
+    fn is_cobol_case(&self) -> bool {
+        self.is_case(Case::Cobol)
+    }
+
+    fn is_upper_dot_case(&self) -> bool {
+        self.is_case(Case::UpperDot)
+    }
+
+    fn is_ada_case(&self) -> bool {
+        self.is_case(Case::Ada)
+    }
+
+    fn is_camel_snake_case(&self) -> bool {
+        self.is_case(Case::CamelSnake)
+    }
+
+    fn is_alternating_case(&self) -> bool {
+        self.is_case(Case::Alternating)
+    }
+
+    fn is_case(&self, case: Case) -> bool {
+        validate(self, case).is_valid()
+    }
 }
 
 #[cfg(test)]
 mod recase_tests {
-    use crate::{Casing, ReCase};
+    use crate::{
+        Case, Casing, DigitBoundary, DigitOutput, EdgeSeparators, Language, Locale, Normalization,
+        Options, ReCase, Sanitizer, Segmentation, SharpS, Sigils,
+    };
 
     #[test]
     fn test_constructor() {
@@ -450,6 +1372,59 @@ mod recase_tests {
         assert_eq!(recase.normal_case(), "ßlong random text");
     }
 
+    #[test]
+    fn test_normalization() {
+        let composed = "Caf\u{e9} D\u{e9}j\u{e0} Vu";
+        let decomposed = "Cafe\u{301} De\u{301}ja\u{300} Vu";
+        for normalization in [Normalization::Nfc, Normalization::Nfd] {
+            let options = Options::new().normalization(normalization);
+            let composed = ReCase::with_options(composed, options.clone());
+            let decomposed = ReCase::with_options(decomposed, options);
+            for case in Case::ALL {
+                assert_eq!(composed.to_case(case), decomposed.to_case(case), "{case}");
+            }
+        }
+
+        let recase =
+            ReCase::with_options(decomposed, Options::new().normalization(Normalization::Nfc));
+        assert_eq!(recase.snake_case(), "caf\u{e9}_d\u{e9}j\u{e0}_vu");
+        let recase =
+            ReCase::with_options(composed, Options::new().normalization(Normalization::Nfd));
+        assert_eq!(recase.kebab_case(), "cafe\u{301}-de\u{301}ja\u{300}-vu");
+
+        let options = Options::new()
+            .locale(Locale::Lt)
+            .normalization(Normalization::Nfc);
+        let recase = ReCase::with_options("i\u{307}\u{300}r", options);
+        assert_eq!(recase.upper_snake_case(), "\u{cc}R");
+        let recase = ReCase::with_options(
+            "ﬁle name",
+            Options::new().normalization(Normalization::Nfkc),
+        );
+        assert_eq!(recase.camel_case(), "fileName");
+    }
+
+    #[test]
+    fn test_titlecase_mapping() {
+        let recase = ReCase::new("ǆungla ǉubav");
+        assert_eq!(recase.pascal_case(), "ǅunglaǈubav");
+        assert_eq!(recase.camel_case(), "ǆunglaǈubav");
+        assert_eq!(recase.title_case(), "ǅungla ǈubav");
+        assert_eq!(recase.sentence_case(), "ǅungla ǉubav");
+        assert_eq!(recase.header_case(), "ǅungla-ǈubav");
+
+        let recase = ReCase::new("ქართული ენა");
+        assert_eq!(recase.title_case(), "ქართული ენა");
+        assert_eq!(recase.upper_snake_case(), "ᲥᲐᲠᲗᲣᲚᲘ_ᲔᲜᲐ");
+
+        let recase = ReCase::new("ßändom ßext");
+        assert_eq!(recase.pascal_case(), "SsändomSsext");
+        let recase = ReCase::with_options("ßändom ßext", Options::new().sharp_s(SharpS::Capital));
+        assert_eq!(recase.pascal_case(), "ẞändomẞext");
+        assert_eq!(recase.title_case(), "ẞändom ẞext");
+        assert_eq!(recase.upper_snake_case(), "SSÄNDOM_SSEXT");
+    }
+
     #[test]
     fn test_camel_case() {
         let recase = ReCase::new("random_text".to_string());
@@ -462,7 +1437,7 @@ mod recase_tests {
         assert_eq!(recase.camel_case(), "rANdomText");
 
         let recase = ReCase::new("ßändom ßext".to_string());
-        assert_eq!(recase.camel_case(), "ßändomSSext");
+        assert_eq!(recase.camel_case(), "ßändomSsext");
     }
 
     #[test]
@@ -471,6 +1446,95 @@ mod recase_tests {
         assert_eq!(recase.upper_snake_case(), "RANDOM_T_E_X_T");
     }
 
+    #[test]
+    fn test_flat_cases() {
+        let recase = ReCase::new("göd_mätsuri ßext".to_string());
+        assert_eq!(recase.flat_case(), "gödmätsurißext");
+        assert_eq!(recase.upper_flat_case(), "GÖDMÄTSURISSEXT");
+        assert_eq!(recase.cobol_case(), "GÖD-MÄTSURI-SSEXT");
+        assert_eq!(recase.upper_dot_case(), "GÖD.MÄTSURI.SSEXT");
+    }
+
+    #[test]
+    fn test_snake_title_cases() {
+        let recase = ReCase::new("誰_randomText".to_string());
+        assert_eq!(recase.ada_case(), "誰_Random_Text");
+        assert_eq!(recase.camel_snake_case(), "誰_Random_Text");
+
+        let recase = ReCase::new("ärger über ärger".to_string());
+        assert_eq!(recase.ada_case(), "Ärger_Über_Ärger");
+        assert_eq!(recase.camel_snake_case(), "ärger_Über_Ärger");
+        assert_eq!(recase.train_case(), "Ärger-Über-Ärger");
+        assert_eq!(recase.lower_sentence_case(), "ärger über ärger");
+    }
+
+    #[test]
+    fn test_edge_separators() {
+        let keep = Options::new().edge_separators(EdgeSeparators::Keep);
+        let map = Options::new().edge_separators(EdgeSeparators::Map);
+
+        let recase = ReCase::with_options("__init__", keep.clone());
+        assert_eq!(recase.snake_case(), "__init__");
+        assert_eq!(recase.kebab_case(), "__init__");
+        assert_eq!(recase.upper_snake_case(), "__INIT__");
+
+        let recase = ReCase::with_options("__typename", keep.clone());
+        assert_eq!(recase.camel_case(), "__typename");
+
+        let recase = ReCase::with_options("trailing_value_", map.clone());
+        assert_eq!(recase.kebab_case(), "trailing-value-");
+        assert_eq!(recase.pascal_case(), "TrailingValue_");
+        assert_eq!(recase.dot_case(), "trailing.value.");
+
+        let recase = ReCase::with_options("__ärger__", map.clone());
+        assert_eq!(recase.title_case(), "  Ärger  ");
+
+        let recase =
+            ReCase::with_options("__init__", map.sanitizer(Sanitizer::new(Language::Python)));
+        assert_eq!(recase.dot_case(), "..init..");
+
+        let recase = ReCase::with_options("___", keep);
+        assert_eq!(recase.snake_case(), "");
+
+        let recase = ReCase::new("__init__");
+        assert_eq!(recase.snake_case(), "init");
+    }
+
+    #[test]
+    fn test_sigils() {
+        let options = Options::new().sigils(Sigils::new());
+        let input = [
+            ("$scope", "$scope", "$Scope"),
+            ("@Annotation", "@annotation", "@Annotation"),
+            ("#channel_name", "#channel_name", "#ChannelName"),
+            ("%macro", "%macro", "%Macro"),
+            (":symbol", ":symbol", ":Symbol"),
+            ("?optionalValue", "?optional_value", "?OptionalValue"),
+            ("is_empty?", "is_empty?", "IsEmpty?"),
+            ("$save!", "$save!", "$Save!"),
+            ("$", "$", "$"),
+            ("plain", "plain", "Plain"),
+        ];
+
+        for (s, snake, pascal) in input {
+            let recase = ReCase::with_options(s, options.clone());
+            assert_eq!(recase.snake_case(), snake);
+            assert_eq!(recase.pascal_case(), pascal);
+        }
+
+        let sigils = Sigils::new().without("#").with_suffix("_");
+        let recase = ReCase::with_options("#channel_name_", Options::new().sigils(sigils));
+        assert_eq!(recase.kebab_case(), "#channel-name_");
+
+        let options = Options::new()
+            .sigils(Sigils::new())
+            .edge_separators(EdgeSeparators::Keep)
+            .sanitizer(Sanitizer::new(Language::Python));
+        let recase = ReCase::with_options("@__Ärger__", options);
+        assert_eq!(recase.snake_case(), "@__ärger__");
+        assert_eq!(recase.pascal_case(), "@__Ärger__");
+    }
+
     #[test]
     fn test_alternating_case() {
         let recase = ReCase::new("random Text".to_string());
@@ -480,6 +1544,40 @@ mod recase_tests {
         assert_eq!(recase.alternating_case(), "誰 RaNdOm TeXt");
     }
 
+    #[test]
+    fn test_acronym_segmentation() {
+        let options = Options::new().segmentation(Segmentation::Acronym);
+
+        let recase = ReCase::with_options("LONG_random_text", options.clone());
+        assert_eq!(recase.normal_case(), "long random text");
+
+        let recase = ReCase::with_options("random_TEXT", options.clone());
+        assert_eq!(recase.upper_snake_case(), "RANDOM_TEXT");
+
+        let recase = ReCase::with_options("HTTPServer", options.clone());
+        assert_eq!(recase.camel_case(), "httpServer");
+
+        assert_eq!(
+            "parseJSONResponse".recase_with(options).pascal_case(),
+            "ParseJsonResponse"
+        );
+    }
+
+    #[test]
+    fn test_digit_output() {
+        let options = Options::new().digit_boundary(DigitBoundary::Both);
+        let recase = ReCase::with_options("version2Update", options.clone());
+        assert_eq!(recase.snake_case(), "version_2_update");
+        assert_eq!(recase.title_case(), "Version 2 Update");
+
+        let options = options.digit_output(DigitOutput::Attached);
+        let recase = ReCase::with_options("version2Update", options);
+        assert_eq!(recase.snake_case(), "version2_update");
+        assert_eq!(recase.upper_snake_case(), "VERSION2_UPDATE");
+        assert_eq!(recase.camel_case(), "version2Update");
+        assert_eq!(recase.title_case(), "Version2 Update");
+    }
+
     #[test]
     fn test_casing_trait() {
         let s = "Hello World";
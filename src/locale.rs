@@ -0,0 +1,427 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::utils;
+
+/// A language whose conventions are followed by locale-aware conversions.
+///
+/// Set with [`Options::locale()`](crate::Options::locale), it selects the lowercasing, uppercasing and
+/// capitalization rules of every output. English, French, German, Spanish and Italian use the default Unicode
+/// mappings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// English, where titles capitalize every word. See [`TitleStyle`](crate::TitleStyle) for style guides.
+    En,
+    /// French.
+    Fr,
+    /// German, whose titles are written in sentence case, as nouns are always capitalized and can't be told apart
+    /// from other words.
+    De,
+    /// Spanish.
+    Es,
+    /// Italian.
+    It,
+    /// Dutch, where a word starting with the `ij` digraph is capitalized as `IJ`, as in `IJsselmeer`.
+    Nl,
+    /// Turkish, where `I` and `ı` are the dotless pair of `İ` and `i`. Titles capitalize every word.
+    Tr,
+    /// Azerbaijani, with the same dotted and dotless `i` as Turkish. Titles capitalize every word.
+    Az,
+    /// Lithuanian, where the dot above `i` and `j` is kept when lowercasing accented capitals, and removed when
+    /// uppercasing. Titles capitalize every word.
+    Lt,
+    /// Greek, where a sigma ending a word lowercases to `ς` and uppercased words lose their accents. Titles
+    /// capitalize every word.
+    El,
+}
+
+impl Locale {
+    /// Returns the articles, conjunctions and prepositions that stay lowercase inside a title. Elided words like
+    /// `l'` end with an apostrophe. English, Turkish, Azerbaijani, Lithuanian and Greek have none, so their titles
+    /// capitalize every word.
+    pub fn minor_words(self) -> &'static [&'static str] {
+        match self {
+            Locale::En => &[],
+            Locale::Fr => FRENCH_MINOR_WORDS,
+            Locale::De => GERMAN_MINOR_WORDS,
+            Locale::Es => SPANISH_MINOR_WORDS,
+            Locale::It => ITALIAN_MINOR_WORDS,
+            Locale::Nl => DUTCH_MINOR_WORDS,
+            Locale::Tr | Locale::Az | Locale::Lt | Locale::El => &[],
+        }
+    }
+
+    /// Returns true if the word stays lowercase inside a title, ignoring the punctuation around it.
+    /// ## Example
+    /// ```
+    /// use recase::Locale;
+    /// assert!(Locale::Es.is_minor_word("y"));
+    /// assert!(Locale::Nl.is_minor_word("van"));
+    /// assert!(!Locale::Fr.is_minor_word("guerre"));
+    /// ```
+    pub fn is_minor_word(self, word: &str) -> bool {
+        utils::is_minor_word(self.minor_words(), word)
+    }
+
+    /// Returns the text lowercased with the rules of the language.
+    /// ## Example
+    /// ```
+    /// use recase::Locale;
+    /// assert_eq!(Locale::Tr.to_lowercase("DİYARBAKIR"), "diyarbakır");
+    /// assert_eq!(Locale::El.to_lowercase("ΟΔΟΣ"), "οδος");
+    /// assert_eq!(Locale::Lt.to_lowercase("Ì"), "i\u{307}\u{300}");
+    /// ```
+    pub fn to_lowercase(self, text: &str) -> String {
+        match self {
+            Locale::Tr | Locale::Az => turkic_lowercase(text),
+            Locale::Lt => lithuanian_lowercase(text),
+            Locale::El => greek_lowercase(text),
+            _ => utils::lowercase(text),
+        }
+    }
+
+    /// Returns the text uppercased with the rules of the language.
+    /// ## Example
+    /// ```
+    /// use recase::Locale;
+    /// assert_eq!(Locale::Tr.to_uppercase("istanbul"), "İSTANBUL");
+    /// assert_eq!(Locale::El.to_uppercase("μάιος"), "ΜΑΪΟΣ");
+    /// assert_eq!(Locale::Lt.to_uppercase("i\u{307}\u{300}"), "I\u{300}");
+    /// ```
+    pub fn to_uppercase(self, text: &str) -> String {
+        match self {
+            Locale::Tr | Locale::Az => text
+                .chars()
+                .map(|c| match c {
+                    'i' => String::from('İ'),
+                    c => c.to_uppercase().collect(),
+                })
+                .collect(),
+            Locale::Lt => lithuanian_uppercase(text),
+            Locale::El => greek_uppercase(text),
+            _ => text.to_uppercase(),
+        }
+    }
+
+    /// Returns the word with its first letter uppercased with the rules of the language.
+    /// ## Example
+    /// ```
+    /// use recase::Locale;
+    /// assert_eq!(Locale::Nl.capitalize("ijsselmeer"), "IJsselmeer");
+    /// assert_eq!(Locale::Tr.capitalize("izmir"), "İzmir");
+    /// assert_eq!(Locale::El.capitalize("άλφα"), "Άλφα");
+    /// ```
+    pub fn capitalize(self, word: &str) -> String {
+        if self == Locale::Nl {
+            if let Some(rest) = word.strip_prefix("ij") {
+                return format!("IJ{rest}");
+            }
+        }
+
+        let mut graphemes = word.graphemes(true);
+        let Some(first) = graphemes.next() else {
+            return String::new();
+        };
+        let mut res = match self {
+            Locale::Tr | Locale::Az | Locale::Lt if first.starts_with(['i', 'j', 'į']) => {
+                self.to_uppercase(first)
+            }
+            // a capitalized Greek word keeps its accent
+            _ => utils::titlecase_first_letter(first),
+        };
+        res.push_str(graphemes.as_str());
+        res
+    }
+
+    /// Splits a word starting with an elided minor word, such as `l'` in `l'homme`, into the elision and the rest.
+    pub(crate) fn split_elision(self, word: &str) -> Option<(&str, &str)> {
+        let apostrophe = word.find(['\'', '’'])?;
+        let end = apostrophe + word[apostrophe..].chars().next()?.len_utf8();
+        let (elision, rest) = word.split_at(end);
+
+        let elision_key = elision.replace('’', "'");
+        (!rest.is_empty() && self.minor_words().contains(&elision_key.as_str()))
+            .then_some((elision, rest))
+    }
+}
+
+fn turkic_lowercase(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            // `I` followed by a combining dot above is a decomposed `İ`
+            'I' if chars.next_if_eq(&'\u{307}').is_some() => res.push('i'),
+            'I' => res.push('ı'),
+            'İ' => res.push('i'),
+            c => res.extend(c.to_lowercase()),
+        }
+    }
+    res
+}
+
+/// Returns true for the combining grave, acute and tilde accents, above which Lithuanian keeps the dot of `i`.
+fn is_lithuanian_accent(c: char) -> bool {
+    matches!(c, '\u{300}' | '\u{301}' | '\u{303}')
+}
+
+fn lithuanian_lowercase(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            'Ì' => res.push_str("i\u{307}\u{300}"),
+            'Í' => res.push_str("i\u{307}\u{301}"),
+            'Ĩ' => res.push_str("i\u{307}\u{303}"),
+            'I' | 'J' | 'Į' if chars.peek().is_some_and(|&next| is_lithuanian_accent(next)) => {
+                res.extend(c.to_lowercase());
+                res.push('\u{307}');
+            }
+            c => res.extend(c.to_lowercase()),
+        }
+    }
+    res
+}
+
+fn lithuanian_uppercase(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut previous = None;
+
+    for c in text.chars() {
+        // the dot above a soft-dotted letter is part of its lowercase form
+        if c != '\u{307}' || !matches!(previous, Some('i' | 'j' | 'į')) {
+            res.extend(c.to_uppercase());
+        }
+        previous = Some(c);
+    }
+    res
+}
+
+fn greek_lowercase(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            'Σ' if res.chars().next_back().is_some_and(char::is_alphabetic)
+                && !chars.peek().is_some_and(|next| next.is_alphabetic()) =>
+            {
+                res.push('ς')
+            }
+            c => res.extend(c.to_lowercase()),
+        }
+    }
+    res
+}
+
+fn greek_uppercase(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    // an accented vowel, after which `ι` and `υ` are pronounced apart and take a dialytika once the accent is gone
+    let mut after_accent = false;
+
+    while let Some(c) = chars.next() {
+        let Some((upper, mut accented, mut dialytika)) = greek_vowel(c) else {
+            res.extend(c.to_uppercase());
+            after_accent = false;
+            continue;
+        };
+        // the marks of a decomposed vowel
+        while let Some(&mark) = chars.peek() {
+            match mark {
+                '\u{300}' | '\u{301}' | '\u{342}' => accented = true,
+                '\u{308}' => dialytika = true,
+                _ => break,
+            }
+            chars.next();
+        }
+
+        match upper {
+            'Ι' if dialytika || (after_accent && !accented) => res.push('Ϊ'),
+            'Υ' if dialytika || (after_accent && !accented) => res.push('Ϋ'),
+            _ => {
+                res.push(upper);
+                if dialytika {
+                    res.push('\u{308}');
+                }
+            }
+        }
+        after_accent = accented;
+    }
+    res
+}
+
+/// Returns the unaccented capital of a Greek vowel, whether it has an accent and whether it has a dialytika.
+fn greek_vowel(c: char) -> Option<(char, bool, bool)> {
+    let vowel = match c {
+        'α' | 'Α' => ('Α', false, false),
+        'ε' | 'Ε' => ('Ε', false, false),
+        'η' | 'Η' => ('Η', false, false),
+        'ι' | 'Ι' => ('Ι', false, false),
+        'ο' | 'Ο' => ('Ο', false, false),
+        'υ' | 'Υ' => ('Υ', false, false),
+        'ω' | 'Ω' => ('Ω', false, false),
+        'ά' | 'Ά' => ('Α', true, false),
+        'έ' | 'Έ' => ('Ε', true, false),
+        'ή' | 'Ή' => ('Η', true, false),
+        'ί' | 'Ί' => ('Ι', true, false),
+        'ό' | 'Ό' => ('Ο', true, false),
+        'ύ' | 'Ύ' => ('Υ', true, false),
+        'ώ' | 'Ώ' => ('Ω', true, false),
+        'ϊ' | 'Ϊ' => ('Ι', false, true),
+        'ϋ' | 'Ϋ' => ('Υ', false, true),
+        'ΐ' => ('Ι', true, true),
+        'ΰ' => ('Υ', true, true),
+        _ => return None,
+    };
+    Some(vowel)
+}
+
+#[rustfmt::skip]
+const FRENCH_MINOR_WORDS: &[&str] = &[
+    "le", "la", "les", "l'", "un", "une", "des", "du", "de", "d'",
+    "et", "ou", "mais", "ni", "car", "que", "qu'",
+    "à", "au", "aux", "en", "dans", "par", "pour", "sur", "sous", "avec", "sans", "chez", "vers", "entre",
+];
+
+#[rustfmt::skip]
+const GERMAN_MINOR_WORDS: &[&str] = &[
+    "der", "die", "das", "den", "dem", "des", "ein", "eine", "einen", "einem", "einer", "eines",
+    "und", "oder", "aber", "denn", "sondern",
+    "an", "am", "auf", "aus", "bei", "beim", "durch", "für", "gegen", "im", "in", "mit", "nach", "ohne", "über",
+    "um", "unter", "von", "vom", "vor", "zu", "zum", "zur", "zwischen",
+];
+
+#[rustfmt::skip]
+const SPANISH_MINOR_WORDS: &[&str] = &[
+    "el", "la", "los", "las", "lo", "un", "una", "unos", "unas",
+    "y", "e", "o", "u", "ni", "pero", "que",
+    "a", "al", "de", "del", "en", "con", "por", "para", "sin", "sobre", "entre", "hasta", "desde", "hacia",
+    "según", "tras",
+];
+
+#[rustfmt::skip]
+const ITALIAN_MINOR_WORDS: &[&str] = &[
+    "il", "lo", "la", "i", "gli", "le", "l'", "un", "uno", "una", "un'",
+    "e", "ed", "o", "od", "ma", "che",
+    "di", "d'", "del", "dello", "della", "dell'", "dei", "degli", "delle", "a", "ad", "al", "allo", "alla",
+    "all'", "ai", "agli", "alle", "da", "dal", "dalla", "in", "nel", "nello", "nella", "nell'", "con", "su",
+    "sul", "sulla", "per", "tra", "fra",
+];
+
+#[rustfmt::skip]
+const DUTCH_MINOR_WORDS: &[&str] = &[
+    "de", "het", "een", "'t", "der", "den",
+    "en", "of", "maar", "dat",
+    "van", "in", "op", "te", "ten", "ter", "aan", "met", "voor", "door", "bij", "uit", "naar", "om", "tot",
+    "over", "onder",
+];
+
+#[cfg(test)]
+mod locale_tests {
+    use unicode_normalization::UnicodeNormalization;
+
+    use crate::{Casing, Locale, Options, ReCase, Segmentation};
+
+    #[test]
+    fn test_case_mapping() {
+        let recase = ReCase::with_options("Istanbul İzmir", Options::new().locale(Locale::Tr));
+        assert_eq!(recase.snake_case(), "ıstanbul_izmir");
+        assert_eq!(recase.upper_snake_case(), "ISTANBUL_İZMİR");
+        assert_eq!(recase.pascal_case(), "Istanbulİzmir");
+        assert_eq!(recase.alternating_case(), "ıStAnBuL iZmİr");
+
+        let recase = ReCase::with_options("kapı_ilçe", Options::new().locale(Locale::Az));
+        assert_eq!(recase.camel_case(), "kapıİlçe");
+
+        let options = Options::new()
+            .segmentation(Segmentation::Acronym)
+            .locale(Locale::El);
+        let recase = ReCase::with_options("ΟΔΟΣ ΑΘΗΝΑΣ", options);
+        assert_eq!(recase.snake_case(), "οδο\u{3c2}_αθηνα\u{3c2}");
+
+        let input = [
+            ("μάιος", "ΜΑΪΟΣ"),
+            ("καλή", "ΚΑΛΗ"),
+            ("ίος", "ΙΟΣ"),
+            ("ώι", "ΩΪ"),
+            ("ύι", "ΥΪ"),
+            ("ΆΙ", "ΑΪ"),
+            ("άυλος", "ΑΫΛΟΣ"),
+            ("αϊ", "ΑΪ"),
+            ("ΐ", "Ϊ"),
+            ("αι", "ΑΙ"),
+        ];
+        for (text, expected) in input {
+            assert_eq!(Locale::El.to_uppercase(text), expected, "{text}");
+            let nfc: String = text.nfc().collect();
+            let nfd: String = text.nfd().collect();
+            assert_eq!(Locale::El.to_uppercase(&nfc), expected, "{text} (NFC)");
+            assert_eq!(Locale::El.to_uppercase(&nfd), expected, "{text} (NFD)");
+        }
+        assert_eq!(Locale::El.to_uppercase("cafe\u{301}"), "CAFE\u{301}");
+
+        let recase = ReCase::with_options("καλή μέρα", Options::new().locale(Locale::El));
+        assert_eq!(recase.upper_snake_case(), "ΚΑΛΗ_ΜΕΡΑ");
+        assert_eq!(recase.title_case(), "Καλή Μέρα");
+
+        let recase = ReCase::with_options("ijsselmeer ijzer", Options::new().locale(Locale::Nl));
+        assert_eq!(recase.title_case(), "IJsselmeer IJzer");
+        assert_eq!(recase.upper_snake_case(), "IJSSELMEER_IJZER");
+
+        let options = Options::new()
+            .segmentation(Segmentation::Acronym)
+            .locale(Locale::Lt);
+        let recase = ReCase::with_options("ÌR ĮJ", options);
+        assert_eq!(recase.kebab_case(), "i\u{307}\u{300}r-įj");
+        assert_eq!(recase.cobol_case(), "I\u{300}R-ĮJ");
+
+        assert_eq!(ReCase::new("Istanbul").snake_case(), "istanbul");
+        assert_eq!(
+            ReCase::new("ijsselmeer").title_case_locale(Locale::Nl),
+            "IJsselmeer"
+        );
+    }
+
+    #[test]
+    fn test_title_case_locale() {
+        let input = [
+            ("guerre et paix", Locale::Fr, "Guerre et Paix"),
+            ("le rouge et le noir", Locale::Fr, "Le Rouge et le Noir"),
+            ("l'homme de l'ouest", Locale::Fr, "L'Homme de l'Ouest"),
+            ("l’étranger", Locale::Fr, "L’Étranger"),
+            ("der herr der ringe", Locale::De, "Der herr der ringe"),
+            ("krieg und frieden", Locale::De, "Krieg und frieden"),
+            ("cien años de soledad", Locale::Es, "Cien Años de Soledad"),
+            ("la casa y el perro", Locale::Es, "La Casa y el Perro"),
+            ("il nome della rosa", Locale::It, "Il Nome della Rosa"),
+            ("l'amica geniale", Locale::It, "L'Amica Geniale"),
+            (
+                "het diner van de familie",
+                Locale::Nl,
+                "Het Diner van de Familie",
+            ),
+            ("the lord of the rings", Locale::En, "The Lord Of The Rings"),
+            (
+                "yüzüklerin efendisi ve kral",
+                Locale::Tr,
+                "Yüzüklerin Efendisi Ve Kral",
+            ),
+            (
+                "manuel d'utilisation: le guide",
+                Locale::Fr,
+                "Manuel d'Utilisation: Le Guide",
+            ),
+        ];
+
+        for (s, locale, expected) in input {
+            assert_eq!(ReCase::new(s).title_case_locale(locale), expected);
+        }
+
+        assert_eq!(
+            "la_vita_e_bella".to_title_case_locale(Locale::It),
+            "La Vita e Bella"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{Case, Language, Options, ReCase, Sanitizer, Segmentation};
+
+/// The kind of item a name is given to in a program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemKind {
+    /// A struct, class, enum, trait or interface.
+    Type,
+    /// A variant of an enum.
+    EnumVariant,
+    /// A free function.
+    Function,
+    /// A function belonging to a type.
+    Method,
+    /// A local variable.
+    Variable,
+    /// A function parameter.
+    Parameter,
+    /// A field or property of a type.
+    Field,
+    /// A constant or a static.
+    Constant,
+    /// A module, package or namespace.
+    Module,
+    /// A database table.
+    Table,
+    /// A database column.
+    Column,
+}
+
+impl Language {
+    /// Returns the options used to name items of the language: the naming profile of the language, if it has one,
+    /// and a [`Sanitizer`] for its keywords.
+    pub fn options(self) -> Options {
+        let options = match self {
+            Language::Rust => Options::rust(),
+            Language::Go => Options::go(),
+            Language::CSharp => Options::dotnet(),
+            _ => Options::new().segmentation(Segmentation::Acronym),
+        };
+        options.sanitizer(Sanitizer::new(self))
+    }
+
+    /// Returns the conventional name of an item of the language, as a valid identifier.
+    /// Go items are named as exported items.
+    /// ## Example
+    /// ```
+    /// use recase::{ItemKind, Language};
+    ///
+    /// assert_eq!(Language::Rust.name(ItemKind::Type, "http server"), String::from("HttpServer"));
+    /// assert_eq!(Language::Rust.name(ItemKind::Constant, "max size"), String::from("MAX_SIZE"));
+    /// assert_eq!(Language::Python.name(ItemKind::Method, "GetValue"), String::from("get_value"));
+    /// assert_eq!(Language::JavaScript.name(ItemKind::Type, "user_id"), String::from("UserId"));
+    /// assert_eq!(Language::Java.name(ItemKind::Module, "com example app"), String::from("com.example.app"));
+    /// assert_eq!(Language::Sql.name(ItemKind::Column, "OrderDate"), String::from("order_date"));
+    /// ```
+    pub fn name<S: Into<String>>(self, kind: ItemKind, text: S) -> String {
+        ReCase::with_options(text, self.options()).to_case(self.case(kind))
+    }
+
+    /// Returns the conventional case of an item of the language.
+    /// Database tables and columns are written in `snake_case` in every language.
+    /// ## Example
+    /// ```
+    /// use recase::{Case, ItemKind, Language};
+    ///
+    /// assert_eq!(Language::Java.case(ItemKind::Constant), Case::UpperSnake);
+    /// assert_eq!(Language::CSharp.case(ItemKind::Method), Case::Pascal);
+    /// ```
+    pub fn case(self, kind: ItemKind) -> Case {
+        use ItemKind::*;
+        use Language::*;
+
+        match (self, kind) {
+            (_, Table | Column) => Case::Snake,
+
+            (Rust, Type | EnumVariant) => Case::Pascal,
+            (Rust, Constant) => Case::UpperSnake,
+            (Rust, _) => Case::Snake,
+
+            (Python, Type) => Case::Pascal,
+            (Python, EnumVariant | Constant) => Case::UpperSnake,
+            (Python, _) => Case::Snake,
+
+            (JavaScript | TypeScript, Type | EnumVariant) => Case::Pascal,
+            (JavaScript | TypeScript, Constant) => Case::UpperSnake,
+            (JavaScript | TypeScript, Module) => Case::Kebab,
+            (JavaScript | TypeScript, _) => Case::Camel,
+
+            (Go, Variable | Parameter) => Case::Camel,
+            (Go, Module) => Case::Flat,
+            (Go, _) => Case::Pascal,
+
+            (Java, Type) => Case::Pascal,
+            (Java, EnumVariant | Constant) => Case::UpperSnake,
+            (Java, Module) => Case::Dot,
+            (Java, _) => Case::Camel,
+
+            (CSharp, Variable | Parameter) => Case::Camel,
+            (CSharp, _) => Case::Pascal,
+
+            (Sql, Constant) => Case::UpperSnake,
+            (Sql, _) => Case::Snake,
+        }
+    }
+}
+
+#[cfg(test)]
+mod naming_tests {
+    use crate::ItemKind::*;
+    use crate::Language::*;
+
+    #[test]
+    fn test_names() {
+        let input = [
+            (Rust, Type, "UUID generator", "UuidGenerator"),
+            (Rust, Function, "parseJSON", "parse_json"),
+            (Rust, Field, "type", "r#type"),
+            (Rust, Module, "self", "self_"),
+            (Python, Constant, "max retries", "MAX_RETRIES"),
+            (Python, Variable, "class", "class_"),
+            (JavaScript, Method, "get_user", "getUser"),
+            (TypeScript, Module, "UserService", "user-service"),
+            (TypeScript, Module, "delete user", "delete-user"),
+            (JavaScript, Module, "import-map", "import-map"),
+            (Go, Function, "serve http", "ServeHTTP"),
+            (Go, Parameter, "user_id", "userID"),
+            (Go, Variable, "type", "type_"),
+            (Go, Module, "http_util", "httputil"),
+            (Java, Field, "2fa code", "_2faCode"),
+            (Java, Module, "org new app", "org.new_.app"),
+            (CSharp, Method, "XMLReader", "XmlReader"),
+            (CSharp, Parameter, "class", "@class"),
+            (Sql, Table, "Order", "\"order\""),
+        ];
+
+        for (language, kind, text, expected) in input {
+            assert_eq!(language.name(kind, text), expected);
+        }
+    }
+}
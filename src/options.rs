@@ -0,0 +1,660 @@
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{AcronymStyle, Acronyms, Locale, Sanitizer, Segmenter};
+
+/// Controls how a run of uppercase letters is split into words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Segmentation {
+    /// Every uppercase letter starts a new word, so `HTTPServer` becomes `h t t p server`.
+    #[default]
+    EveryCapital,
+    /// A run of uppercase letters is kept as one word, and its last letter starts the next word
+    /// when it is followed by a lowercase letter, so `HTTPServer` becomes `http server`.
+    Acronym,
+}
+
+/// Controls whether a transition between a letter and a digit starts a new word.
+/// Any Unicode decimal digit (e.g. `٣` or `३`) counts as a digit, but not other numeric characters such as `²`,
+/// `½`, `①` or `Ⅻ`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DigitBoundary {
+    /// Digits are ordinary word characters, so `version2Update` becomes `version2 update`.
+    #[default]
+    Never,
+    /// A digit following a letter starts a new word, so `utf8Decoder` becomes `utf 8 decoder`.
+    BeforeDigits,
+    /// A letter following a digit starts a new word, so `ipv4address` becomes `ipv4 address`.
+    AfterDigits,
+    /// Both transitions start a new word, so `ipv4address` becomes `ipv 4 address`.
+    Both,
+}
+
+/// Controls how a word starting with a digit is joined to the word before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DigitOutput {
+    /// The word is separated like any other word, e.g. `version_2`.
+    #[default]
+    Separated,
+    /// The word is attached to the previous word without a separator, e.g. `version2`.
+    Attached,
+}
+
+/// Controls what happens to the separators before the first word and after the last one, like the underscores of
+/// `_private`, `__init__` or `trailing_`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgeSeparators {
+    /// They are dropped, so `__init__` becomes `init`.
+    #[default]
+    Drop,
+    /// They are written as they are in every output, so `__init__` becomes `__init__` in `kebab-case`.
+    Keep,
+    /// Each of them is replaced by the separator of the output, so `__init__` becomes `--init--` in `kebab-case`.
+    /// They are written as they are in outputs without separator, such as `camelCase`.
+    Map,
+}
+
+/// Controls how `ß` is capitalized at the start of a word, since the letter has no single titlecase mapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SharpS {
+    /// It becomes `Ss`, its Unicode titlecase mapping, e.g. `ßext` -> `Ssext`.
+    #[default]
+    Ss,
+    /// It becomes the capital `ẞ`, e.g. `ßext` -> `ẞext`.
+    Capital,
+}
+
+/// A Unicode normalization form, applied to the input before it is split into words and to every output, so that
+/// text composed differently, like `é` typed as one character or as `e` and a combining accent, gives the same
+/// output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Canonical composition, used by most text.
+    Nfc,
+    /// Canonical decomposition, used by macOS file names.
+    Nfd,
+    /// Compatibility composition, which also replaces variants like `ﬁ` or `①` by their plain form.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+impl Normalization {
+    /// Returns the text in the normalization form.
+    pub(crate) fn normalize(self, text: &str) -> String {
+        match self {
+            Normalization::Nfc => text.nfc().collect(),
+            Normalization::Nfd => text.nfd().collect(),
+            Normalization::Nfkc => text.nfkc().collect(),
+            Normalization::Nfkd => text.nfkd().collect(),
+        }
+    }
+}
+
+type Predicate = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
+/// The set of graphemes that separate words. They are dropped from the output.
+/// # Example
+/// ```
+/// use recase::{Options, ReCase, Separators};
+///
+/// let separators = Separators::new().with("+").without(".");
+/// let recase = ReCase::with_options("v1.2.3+build", Options::new().separators(separators));
+/// assert_eq!(recase.snake_case(), String::from("v1.2.3_build"));
+/// ```
+#[derive(Clone)]
+pub struct Separators {
+    graphemes: Vec<String>,
+    predicate: Option<Predicate>,
+}
+
+impl Separators {
+    /// The separators used by default: ` `, `.`, `/`, `_`, `-` and `\`.
+    pub const DEFAULT: [&'static str; 6] = [" ", ".", "/", "_", "-", "\\"];
+
+    /// Create the default set of separators.
+    pub fn new() -> Separators {
+        Separators::default()
+    }
+
+    /// Create an empty set of separators.
+    pub fn none() -> Separators {
+        Separators {
+            graphemes: vec![],
+            predicate: None,
+        }
+    }
+
+    /// Create a set of separators from a predicate, which is called for every grapheme of the input.
+    /// Graphemes added with `with()` are separators as well.
+    /// ## Example
+    /// ```
+    /// use recase::{Options, ReCase, Separators};
+    ///
+    /// let separators = Separators::from_fn(|g| g.chars().all(|c| c.is_ascii_punctuation()));
+    /// let recase = ReCase::with_options("god+matsuri?", Options::new().separators(separators));
+    /// assert_eq!(recase.snake_case(), String::from("god_matsuri"));
+    /// ```
+    pub fn from_fn<F>(predicate: F) -> Separators
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        Separators {
+            graphemes: vec![],
+            predicate: Some(Arc::new(predicate)),
+        }
+    }
+
+    /// Adds a grapheme to the set.
+    pub fn with<S: Into<String>>(mut self, grapheme: S) -> Separators {
+        let grapheme = grapheme.into();
+        if !self.graphemes.contains(&grapheme) {
+            self.graphemes.push(grapheme);
+        }
+        self
+    }
+
+    /// Removes a grapheme that was added to the set. It does not affect the predicate.
+    pub fn without(mut self, grapheme: &str) -> Separators {
+        self.graphemes.retain(|g| g != grapheme);
+        self
+    }
+
+    /// Returns true if the grapheme separates words.
+    pub fn contains(&self, grapheme: &str) -> bool {
+        self.graphemes.iter().any(|g| g == grapheme)
+            || self.predicate.as_ref().is_some_and(|p| p(grapheme))
+    }
+}
+
+impl Default for Separators {
+    fn default() -> Separators {
+        Separators {
+            graphemes: Separators::DEFAULT.iter().map(|s| s.to_string()).collect(),
+            predicate: None,
+        }
+    }
+}
+
+impl fmt::Debug for Separators {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Separators")
+            .field("graphemes", &self.graphemes)
+            .field("predicate", &self.predicate.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+/// The prefixes and suffixes that are not part of the words of an input, like the `$` of `$scope` or the `?` of
+/// `empty?`. They are written as they are before and after every output, and the longest one is matched first.
+/// # Example
+/// ```
+/// use recase::{Options, ReCase, Sigils};
+///
+/// let recase = ReCase::with_options("@ManyToOne", Options::new().sigils(Sigils::new()));
+/// assert_eq!(recase.snake_case(), String::from("@many_to_one"));
+///
+/// let sigils = Sigils::none().with_prefix("@@");
+/// let recase = ReCase::with_options("@@classVar", Options::new().sigils(sigils));
+/// assert_eq!(recase.snake_case(), String::from("@@class_var"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sigils {
+    prefixes: Vec<String>,
+    suffixes: Vec<String>,
+}
+
+impl Sigils {
+    /// The prefixes recognized by default: `$`, `@`, `#`, `%`, `:` and `?`.
+    pub const PREFIXES: [&'static str; 6] = ["$", "@", "#", "%", ":", "?"];
+    /// The suffixes recognized by default: `?` and `!`.
+    pub const SUFFIXES: [&'static str; 2] = ["?", "!"];
+
+    /// Create the default set of sigils.
+    pub fn new() -> Sigils {
+        Sigils::default()
+    }
+
+    /// Create an empty set of sigils.
+    pub fn none() -> Sigils {
+        Sigils {
+            prefixes: vec![],
+            suffixes: vec![],
+        }
+    }
+
+    /// Adds a prefix to the set.
+    pub fn with_prefix<S: Into<String>>(mut self, prefix: S) -> Sigils {
+        Sigils::insert(&mut self.prefixes, prefix.into());
+        self
+    }
+
+    /// Adds a suffix to the set.
+    pub fn with_suffix<S: Into<String>>(mut self, suffix: S) -> Sigils {
+        Sigils::insert(&mut self.suffixes, suffix.into());
+        self
+    }
+
+    /// Removes a sigil from the prefixes and suffixes of the set.
+    pub fn without(mut self, sigil: &str) -> Sigils {
+        self.prefixes.retain(|s| s != sigil);
+        self.suffixes.retain(|s| s != sigil);
+        self
+    }
+
+    /// Returns the byte range of the input between its sigils. An input made only of sigils has none.
+    pub(crate) fn body(&self, input: &str) -> Range<usize> {
+        let prefix = self
+            .prefixes
+            .iter()
+            .find(|prefix| input.starts_with(prefix.as_str()))
+            .map_or(0, |prefix| prefix.len());
+        let suffix = self
+            .suffixes
+            .iter()
+            .find(|suffix| input[prefix..].ends_with(suffix.as_str()))
+            .map_or(0, |suffix| suffix.len());
+
+        if prefix + suffix < input.len() {
+            prefix..input.len() - suffix
+        } else {
+            0..input.len()
+        }
+    }
+
+    /// Inserts a sigil, keeping the longest ones first.
+    fn insert(sigils: &mut Vec<String>, sigil: String) {
+        if sigil.is_empty() || sigils.contains(&sigil) {
+            return;
+        }
+        let index = sigils.partition_point(|s| s.len() >= sigil.len());
+        sigils.insert(index, sigil);
+    }
+}
+
+impl Default for Sigils {
+    fn default() -> Sigils {
+        let sigils = Sigils::PREFIXES
+            .iter()
+            .fold(Sigils::none(), |sigils, prefix| sigils.with_prefix(*prefix));
+        Sigils::SUFFIXES
+            .iter()
+            .fold(sigils, |sigils, suffix| sigils.with_suffix(*suffix))
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct SharedSegmenter(Arc<dyn Segmenter>);
+
+impl std::ops::Deref for SharedSegmenter {
+    type Target = dyn Segmenter;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+impl fmt::Debug for SharedSegmenter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Segmenter(..)")
+    }
+}
+
+/// Options used to construct a [`ReCase`](crate::ReCase) instance.
+/// # Example
+/// ```
+/// use recase::{Options, ReCase, Segmentation};
+///
+/// let options = Options::new().segmentation(Segmentation::Acronym);
+/// let recase = ReCase::with_options("parseJSONResponse", options);
+/// assert_eq!(recase.snake_case(), String::from("parse_json_response"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub(crate) segmentation: Segmentation,
+    pub(crate) digit_boundary: DigitBoundary,
+    pub(crate) digit_output: DigitOutput,
+    pub(crate) edge_separators: EdgeSeparators,
+    pub(crate) separators: Separators,
+    pub(crate) segmenter: Option<SharedSegmenter>,
+    pub(crate) acronyms: Acronyms,
+    pub(crate) acronym_style: AcronymStyle,
+    pub(crate) join_single_letters: bool,
+    pub(crate) uncased_separator: Option<String>,
+    pub(crate) sanitizer: Option<Sanitizer>,
+    pub(crate) sigils: Option<Sigils>,
+    pub(crate) locale: Option<Locale>,
+    pub(crate) sharp_s: SharpS,
+    pub(crate) normalization: Option<Normalization>,
+}
+
+impl Options {
+    /// Create a new set of options, equivalent to the ones used by `ReCase::new()`.
+    pub fn new() -> Options {
+        Options::default()
+    }
+
+    /// The options used to read the words of an input whose case is being detected, validated or inferred, so
+    /// that runs of capitals like `HTTP` stay one word.
+    pub(crate) fn detection() -> Options {
+        Options::new().segmentation(Segmentation::Acronym)
+    }
+
+    /// Create the options of the Go naming profile, following golint: the [`GO_INITIALISMS`](Acronyms::GO_INITIALISMS)
+    /// are fully capitalized in `camelCase` and `PascalCase` outputs, but lowercased when they start a `camelCase`
+    /// name. The list can be extended by setting `Acronyms::go().with(..)` as acronyms.
+    /// ## Example
+    /// ```
+    /// use recase::{Acronyms, Options, ReCase};
+    ///
+    /// let recase = ReCase::with_options("user_id", Options::go());
+    /// assert_eq!(recase.camel_case(), String::from("userID"));
+    ///
+    /// let recase = ReCase::with_options("HttpServer", Options::go());
+    /// assert_eq!(recase.camel_case(), String::from("httpServer"));
+    /// assert_eq!(recase.pascal_case(), String::from("HTTPServer"));
+    ///
+    /// let options = Options::go().acronyms(Acronyms::go().with("GRPC"));
+    /// let recase = ReCase::with_options("new_grpc_client", options);
+    /// assert_eq!(recase.pascal_case(), String::from("NewGRPCClient"));
+    /// ```
+    pub fn go() -> Options {
+        Options::new()
+            .segmentation(Segmentation::Acronym)
+            .acronyms(Acronyms::go())
+            .acronym_style(AcronymStyle::Canonical)
+    }
+
+    /// Create the options of the .NET naming profile, following the .NET capitalization conventions: acronyms of
+    /// two letters written in capitals stay capitalized, longer ones are capitalized like words, and `Id` and `Ok`
+    /// are compound words rather than acronyms. More acronyms can be registered by setting
+    /// `Acronyms::dotnet().with(..)` as acronyms.
+    /// ## Example
+    /// ```
+    /// use recase::{Options, ReCase};
+    ///
+    /// let recase = ReCase::with_options("IOStream", Options::dotnet());
+    /// assert_eq!(recase.pascal_case(), String::from("IOStream"));
+    /// assert_eq!(recase.camel_case(), String::from("ioStream"));
+    ///
+    /// let recase = ReCase::with_options("XMLReader", Options::dotnet());
+    /// assert_eq!(recase.pascal_case(), String::from("XmlReader"));
+    ///
+    /// let recase = ReCase::with_options("userID", Options::dotnet());
+    /// assert_eq!(recase.pascal_case(), String::from("UserId"));
+    /// ```
+    pub fn dotnet() -> Options {
+        Options::new()
+            .segmentation(Segmentation::Acronym)
+            .acronyms(Acronyms::dotnet())
+            .acronym_style(AcronymStyle::TwoLetterUpper)
+    }
+
+    /// Create the options of the Rust naming profile, following RFC 430: acronyms are one word in `PascalCase`
+    /// (`Uuid`, `HttpServer`) and single letters are attached to the next word in `snake_case` and
+    /// `UPPER_SNAKE_CASE` (`btree_map`, `PI_2`). Words meeting on characters without case are separated by an
+    /// underscore in `PascalCase` (`Vec2_3`), so that the outputs pass the `non_camel_case_types`,
+    /// `non_snake_case` and `non_upper_case_globals` lints.
+    /// ## Example
+    /// ```
+    /// use recase::{Options, ReCase};
+    ///
+    /// let recase = ReCase::with_options("HTTPServer", Options::rust());
+    /// assert_eq!(recase.pascal_case(), String::from("HttpServer"));
+    ///
+    /// let recase = ReCase::with_options("BTreeMap", Options::rust());
+    /// assert_eq!(recase.snake_case(), String::from("btree_map"));
+    /// assert_eq!(recase.upper_snake_case(), String::from("BTREE_MAP"));
+    /// ```
+    pub fn rust() -> Options {
+        Options::new()
+            .segmentation(Segmentation::Acronym)
+            .join_single_letters(true)
+            .uncased_separator("_")
+    }
+
+    /// Sets how runs of uppercase letters are split into words.
+    pub fn segmentation(mut self, segmentation: Segmentation) -> Options {
+        self.segmentation = segmentation;
+        self
+    }
+
+    /// Sets which letter/digit transitions start a new word.
+    pub fn digit_boundary(mut self, digit_boundary: DigitBoundary) -> Options {
+        self.digit_boundary = digit_boundary;
+        self
+    }
+
+    /// Sets how words starting with a digit are joined to the previous word in every output.
+    pub fn digit_output(mut self, digit_output: DigitOutput) -> Options {
+        self.digit_output = digit_output;
+        self
+    }
+
+    /// Sets what happens to the separators before the first word and after the last one in every output.
+    /// ## Example
+    /// ```
+    /// use recase::{EdgeSeparators, Options, ReCase};
+    ///
+    /// let recase = ReCase::with_options("__init__", Options::new().edge_separators(EdgeSeparators::Keep));
+    /// assert_eq!(recase.pascal_case(), String::from("__Init__"));
+    ///
+    /// let recase = ReCase::with_options("_private", Options::new().edge_separators(EdgeSeparators::Map));
+    /// assert_eq!(recase.kebab_case(), String::from("-private"));
+    /// ```
+    pub fn edge_separators(mut self, edge_separators: EdgeSeparators) -> Options {
+        self.edge_separators = edge_separators;
+        self
+    }
+
+    /// Sets the graphemes that separate words.
+    pub fn separators(mut self, separators: Separators) -> Options {
+        self.separators = separators;
+        self
+    }
+
+    /// Sets the segmenter used to split the input into words, replacing the [`DefaultSegmenter`](crate::DefaultSegmenter).
+    /// The segmentation, digit boundary and separator options are not used by custom segmenters.
+    pub fn segmenter<T: Segmenter + 'static>(mut self, segmenter: T) -> Options {
+        self.segmenter = Some(SharedSegmenter(Arc::new(segmenter)));
+        self
+    }
+
+    /// Sets the registry of acronyms that are never split apart.
+    pub fn acronyms(mut self, acronyms: Acronyms) -> Options {
+        self.acronyms = acronyms;
+        self
+    }
+
+    /// Sets how registered acronyms are written by `camelCase`, `PascalCase`, `Title Case`, `Sentence case`,
+    /// `Header-Case`, `Ada_Case` and `camel_Snake_Case` outputs.
+    pub fn acronym_style(mut self, acronym_style: AcronymStyle) -> Options {
+        self.acronym_style = acronym_style;
+        self
+    }
+
+    /// Attaches a word made of a single letter to the next word in `snake_case` and `UPPER_SNAKE_CASE` outputs,
+    /// so `b tree map` becomes `btree_map`.
+    pub fn join_single_letters(mut self, join_single_letters: bool) -> Options {
+        self.join_single_letters = join_single_letters;
+        self
+    }
+
+    /// Sets a separator written between words that would otherwise be joined without one, such as in `PascalCase`,
+    /// when the characters they meet on have no case, so `vec2 3` becomes `Vec2_3` rather than `Vec23`.
+    pub fn uncased_separator<S: Into<String>>(mut self, separator: S) -> Options {
+        self.uncased_separator = Some(separator.into());
+        self
+    }
+
+    /// Sets a sanitizer that turns every output into a valid identifier of its language.
+    pub fn sanitizer(mut self, sanitizer: Sanitizer) -> Options {
+        self.sanitizer = Some(sanitizer);
+        self
+    }
+
+    /// Sets the language whose lowercasing, uppercasing and capitalization rules are used by every output,
+    /// instead of the locale-independent Unicode mappings.
+    /// ## Example
+    /// ```
+    /// use recase::{Locale, Options, ReCase};
+    ///
+    /// let recase = ReCase::with_options("Istanbul", Options::new().locale(Locale::Tr));
+    /// assert_eq!(recase.snake_case(), String::from("ıstanbul"));
+    /// ```
+    pub fn locale(mut self, locale: Locale) -> Options {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Sets how `ß` is capitalized when it starts a word of a capitalized output, like `PascalCase` or
+    /// `Title Case`.
+    /// ## Example
+    /// ```
+    /// use recase::{Options, ReCase, SharpS};
+    ///
+    /// assert_eq!(ReCase::new("ßext").pascal_case(), String::from("Ssext"));
+    ///
+    /// let recase = ReCase::with_options("ßext", Options::new().sharp_s(SharpS::Capital));
+    /// assert_eq!(recase.pascal_case(), String::from("ẞext"));
+    /// ```
+    pub fn sharp_s(mut self, sharp_s: SharpS) -> Options {
+        self.sharp_s = sharp_s;
+        self
+    }
+
+    /// Sets the Unicode normalization form of the input, applied before it is split into words, and of every
+    /// output.
+    /// ## Example
+    /// ```
+    /// use recase::{Normalization, Options, ReCase};
+    ///
+    /// let options = Options::new().normalization(Normalization::Nfc);
+    /// let composed = ReCase::with_options("Caf\u{e9} Cr\u{e8}me", options.clone());
+    /// let decomposed = ReCase::with_options("Cafe\u{301} Cre\u{300}me", options);
+    /// assert_eq!(composed.snake_case(), decomposed.snake_case());
+    /// assert_eq!(decomposed.snake_case(), String::from("caf\u{e9}_cr\u{e8}me"));
+    /// ```
+    pub fn normalization(mut self, normalization: Normalization) -> Options {
+        self.normalization = Some(normalization);
+        self
+    }
+
+    /// Sets the sigils kept as they are before and after the words of every output, such as `$` in `$scope`.
+    /// They are not sanitized.
+    pub fn sigils(mut self, sigils: Sigils) -> Options {
+        self.sigils = Some(sigils);
+        self
+    }
+}
+
+#[cfg(test)]
+mod options_tests {
+    use crate::{Acronyms, Casing, Options, ReCase};
+
+    // the checks of rustc's `non_camel_case_types`, `non_snake_case` and `non_upper_case_globals` lints
+    fn is_rust_camel_case(name: &str) -> bool {
+        let name = name.trim_matches('_');
+        let has_case = |c: char| c.is_lowercase() || c.is_uppercase();
+        let chars: Vec<char> = name.chars().collect();
+
+        name.is_empty()
+            || (!chars[0].is_lowercase()
+                && !name.contains("__")
+                && !chars
+                    .windows(2)
+                    .any(|w| (has_case(w[0]) && w[1] == '_') || (has_case(w[1]) && w[0] == '_')))
+    }
+
+    fn is_rust_snake_case(name: &str) -> bool {
+        let name = name.trim_matches('_');
+        !name.contains("__") && !name.chars().any(char::is_uppercase)
+    }
+
+    fn is_rust_upper_case(name: &str) -> bool {
+        !name.chars().any(char::is_lowercase)
+    }
+
+    #[test]
+    fn test_go_profile() {
+        let input = [
+            ("user_id", "userID", "UserID"),
+            ("HTTPServer", "httpServer", "HTTPServer"),
+            ("serve_http", "serveHTTP", "ServeHTTP"),
+            ("xmlHTTPRequest", "xmlHTTPRequest", "XMLHTTPRequest"),
+            ("UTF8Decoder", "utf8Decoder", "UTF8Decoder"),
+            ("api-url", "apiURL", "APIURL"),
+            ("identity", "identity", "Identity"),
+            ("IDENTITY", "identity", "Identity"),
+        ];
+
+        for (s, camel, pascal) in input {
+            let recase = ReCase::with_options(s, Options::go());
+            assert_eq!(recase.camel_case(), camel);
+            assert_eq!(recase.pascal_case(), pascal);
+        }
+
+        assert_eq!(
+            "JsonApi".recase_with(Options::go()).snake_case(),
+            "json_api"
+        );
+    }
+
+    #[test]
+    fn test_dotnet_profile() {
+        let input = [
+            ("IOStream", "ioStream", "IOStream"),
+            ("DBConnection", "dbConnection", "DBConnection"),
+            ("XMLReader", "xmlReader", "XmlReader"),
+            ("html_parser", "htmlParser", "HtmlParser"),
+            ("get_user_ID", "getUserId", "GetUserId"),
+            ("OKButton", "okButton", "OkButton"),
+            ("io_stream", "ioStream", "IoStream"),
+            ("Ä_Ö_ÜX", "äÖÜX", "ÄÖÜX"),
+        ];
+
+        for (s, camel, pascal) in input {
+            let recase = ReCase::with_options(s, Options::dotnet());
+            assert_eq!(recase.camel_case(), camel);
+            assert_eq!(recase.pascal_case(), pascal);
+        }
+
+        let options = Options::dotnet().acronyms(Acronyms::dotnet().with("IO"));
+        assert_eq!("io_stream".recase_with(options).pascal_case(), "IOStream");
+    }
+
+    #[test]
+    fn test_rust_profile() {
+        let input = [
+            ("UUID", "Uuid", "uuid", "UUID"),
+            ("stdin", "Stdin", "stdin", "STDIN"),
+            ("HTTPServer", "HttpServer", "http_server", "HTTP_SERVER"),
+            ("BTreeMap", "BTreeMap", "btree_map", "BTREE_MAP"),
+            ("PI_2", "Pi2", "pi_2", "PI_2"),
+            ("vec2 3", "Vec2_3", "vec2_3", "VEC2_3"),
+            ("a b", "AB", "ab", "AB"),
+            ("版本 2", "版本_2", "版本_2", "版本_2"),
+            (
+                "Löng ẽxample",
+                "LöngẼxample",
+                "löng_ẽxample",
+                "LÖNG_ẼXAMPLE",
+            ),
+            ("ßtraße", "Sstraße", "ßtraße", "SSTRASSE"),
+        ];
+
+        for (s, pascal, snake, upper_snake) in input {
+            let recase = ReCase::with_options(s, Options::rust());
+            assert_eq!(recase.pascal_case(), pascal);
+            assert_eq!(recase.snake_case(), snake);
+            assert_eq!(recase.upper_snake_case(), upper_snake);
+
+            assert!(is_rust_camel_case(&recase.pascal_case()));
+            assert!(is_rust_snake_case(&recase.snake_case()));
+            assert!(is_rust_upper_case(&recase.upper_snake_case()));
+        }
+    }
+}
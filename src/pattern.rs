@@ -0,0 +1,428 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{CustomCase, WordCase};
+
+/// The words a placeholder of a pattern applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Selector {
+    First,
+    Rest,
+    All,
+}
+
+#[derive(Debug)]
+struct Placeholder {
+    position: usize,
+    selector: Selector,
+    word_case: WordCase,
+    separator: Option<String>,
+}
+
+#[derive(Debug)]
+enum Token {
+    Literal { position: usize, text: String },
+    Placeholder(Placeholder),
+}
+
+impl FromStr for CustomCase {
+    type Err = PatternError;
+
+    /// Parses a case from a pattern such as `{first:lower}{rest:Title}`, `{all:UPPER|_}` or `m_{all:lower|_}`.
+    ///
+    /// A pattern is made of either an `{all:..}` placeholder, or a `{first:..}` placeholder directly followed by a
+    /// `{rest:..}` one, which can be surrounded by literal text used as prefix and suffix. A placeholder names the
+    /// casing of its words, `lower`, `UPPER` or `Title` (whatever their case), optionally followed by `|` and the
+    /// separator written between words, in which `\\`, `\}` and `\|` are a backslash, a brace and a bar. Braces
+    /// are written `{{` and `}}` in literal text.
+    /// ## Example
+    /// ```
+    /// use recase::{CustomCase, ReCase};
+    ///
+    /// let recase = ReCase::new("example string");
+    /// let case: CustomCase = "m_{all:lower|_}".parse().unwrap();
+    /// assert_eq!(recase.custom_case(&case), String::from("m_example_string"));
+    ///
+    /// let case: CustomCase = "{first:lower}{rest:Title}".parse().unwrap();
+    /// assert_eq!(recase.custom_case(&case), String::from("exampleString"));
+    ///
+    /// let error = "{all:Upper|_".parse::<CustomCase>().unwrap_err();
+    /// assert_eq!(error.position(), 0);
+    /// assert_eq!(error.to_string(), "unclosed placeholder at position 0");
+    /// ```
+    fn from_str(pattern: &str) -> Result<CustomCase, PatternError> {
+        let tokens = tokenize(pattern)?;
+
+        let mut prefix = String::new();
+        let mut suffix: Option<(usize, String)> = None;
+        let mut placeholders: Vec<Placeholder> = vec![];
+        for token in tokens {
+            match token {
+                Token::Literal { text, .. } if placeholders.is_empty() => prefix = text,
+                Token::Literal { position, text } => suffix = Some((position, text)),
+                Token::Placeholder(placeholder) => {
+                    if let Some((position, _)) = suffix {
+                        return Err(PatternError::new(
+                            position,
+                            PatternErrorKind::UnexpectedLiteral,
+                        ));
+                    }
+                    let expected = match placeholders.as_slice() {
+                        [] => placeholder.selector != Selector::Rest,
+                        [previous] => {
+                            previous.selector == Selector::First
+                                && placeholder.selector == Selector::Rest
+                        }
+                        _ => false,
+                    };
+                    if !expected {
+                        return Err(PatternError::new(
+                            placeholder.position,
+                            PatternErrorKind::UnexpectedPlaceholder,
+                        ));
+                    }
+                    placeholders.push(placeholder);
+                }
+            }
+        }
+        let suffix = suffix.map(|(_, text)| text).unwrap_or_default();
+
+        match placeholders.as_slice() {
+            [all] if all.selector == Selector::All => Ok(CustomCase {
+                separator: all.separator.clone().unwrap_or_default(),
+                first_word: all.word_case,
+                other_words: all.word_case,
+                prefix,
+                suffix,
+            }),
+            [first, rest] => {
+                let separator = match (&first.separator, &rest.separator) {
+                    (Some(a), Some(b)) if a != b => {
+                        return Err(PatternError::new(
+                            rest.position,
+                            PatternErrorKind::ConflictingSeparators,
+                        ))
+                    }
+                    (Some(separator), _) | (None, Some(separator)) => separator.clone(),
+                    (None, None) => String::new(),
+                };
+                Ok(CustomCase {
+                    separator,
+                    first_word: first.word_case,
+                    other_words: rest.word_case,
+                    prefix,
+                    suffix,
+                })
+            }
+            _ => Err(PatternError::new(
+                pattern.len(),
+                PatternErrorKind::MissingPlaceholder,
+            )),
+        }
+    }
+}
+
+/// Splits a pattern into literal texts and placeholders.
+fn tokenize(pattern: &str) -> Result<Vec<Token>, PatternError> {
+    let mut tokens = vec![];
+    let mut literal = String::new();
+    let mut literal_start = 0;
+    let mut chars = pattern.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().is_some_and(|&(_, next)| next == '{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek().is_some_and(|&(_, next)| next == '}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '}' => return Err(PatternError::new(i, PatternErrorKind::UnmatchedBrace)),
+            '{' => {
+                // the content before the first unescaped `|`, and the separator after it
+                let mut content = String::new();
+                let mut separator: Option<(usize, String)> = None;
+                let end = loop {
+                    let c = match chars.next() {
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, escaped)) => escaped,
+                            None => break None,
+                        },
+                        Some((end, '}')) => break Some(end),
+                        Some((j, '|')) if separator.is_none() => {
+                            separator = Some((j, String::new()));
+                            continue;
+                        }
+                        Some((_, c)) => c,
+                        None => break None,
+                    };
+                    match &mut separator {
+                        Some((_, separator)) => separator.push(c),
+                        None => content.push(c),
+                    }
+                };
+                let end = end.ok_or(PatternError::new(i, PatternErrorKind::UnclosedPlaceholder))?;
+                if !literal.is_empty() {
+                    tokens.push(Token::Literal {
+                        position: literal_start,
+                        text: std::mem::take(&mut literal),
+                    });
+                }
+                tokens.push(Token::Placeholder(parse_placeholder(
+                    i + 1,
+                    &content,
+                    separator,
+                )?));
+                literal_start = end + 1;
+            }
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        tokens.push(Token::Literal {
+            position: literal_start,
+            text: literal,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Parses the content of a placeholder starting at `start`, `selector:word_case`, and the separator following its
+/// `|` if it has one, with its position.
+fn parse_placeholder(
+    start: usize,
+    content: &str,
+    separator: Option<(usize, String)>,
+) -> Result<Placeholder, PatternError> {
+    let (selector, word_case) = content.split_once(':').unwrap_or((content, ""));
+    let selector = match selector.trim() {
+        "first" => Selector::First,
+        "rest" => Selector::Rest,
+        "all" => Selector::All,
+        other => {
+            return Err(PatternError::new(
+                start,
+                PatternErrorKind::UnknownSelector(other.to_owned()),
+            ))
+        }
+    };
+
+    let word_case_start = start + content.find(':').map_or(content.len(), |colon| colon + 1);
+    if !content.contains(':') {
+        return Err(PatternError::new(
+            word_case_start,
+            PatternErrorKind::MissingWordCase,
+        ));
+    }
+    let word_case = match word_case.trim().to_lowercase().as_str() {
+        "lower" => WordCase::Lower,
+        "upper" => WordCase::Upper,
+        "title" => WordCase::Title,
+        _ => {
+            return Err(PatternError::new(
+                word_case_start,
+                PatternErrorKind::UnknownWordCase(word_case.trim().to_owned()),
+            ))
+        }
+    };
+
+    // an empty separator is written by leaving out the `|`
+    let separator = match separator {
+        Some((position, separator)) if separator.is_empty() => {
+            return Err(PatternError::new(
+                position,
+                PatternErrorKind::EmptySeparator,
+            ))
+        }
+        separator => separator.map(|(_, separator)| separator),
+    };
+
+    Ok(Placeholder {
+        position: start - 1,
+        selector,
+        word_case,
+        separator,
+    })
+}
+
+/// What is wrong with a pattern that can't be parsed as a [`CustomCase`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternErrorKind {
+    /// A `{` without the `}` closing its placeholder.
+    UnclosedPlaceholder,
+    /// A `}` that closes no placeholder, which is written `}}` in literal text.
+    UnmatchedBrace,
+    /// A placeholder that doesn't start with `first`, `rest` or `all`.
+    UnknownSelector(String),
+    /// A placeholder without `:` and the casing of its words.
+    MissingWordCase,
+    /// A casing that is not `lower`, `UPPER` or `Title`.
+    UnknownWordCase(String),
+    /// A placeholder that is repeated, or out of the `{all:..}` or `{first:..}{rest:..}` order.
+    UnexpectedPlaceholder,
+    /// Literal text between placeholders.
+    UnexpectedLiteral,
+    /// A `|` without a separator after it.
+    EmptySeparator,
+    /// Different separators in the `first` and `rest` placeholders.
+    ConflictingSeparators,
+    /// A pattern without `{all:..}` or `{first:..}{rest:..}` placeholders.
+    MissingPlaceholder,
+}
+
+/// The error returned when parsing an invalid [`CustomCase`] pattern, with the byte position in the pattern where
+/// the problem was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternError {
+    position: usize,
+    kind: PatternErrorKind,
+}
+
+impl PatternError {
+    fn new(position: usize, kind: PatternErrorKind) -> PatternError {
+        PatternError { position, kind }
+    }
+
+    /// Returns the byte position in the pattern where the problem was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns what is wrong with the pattern.
+    pub fn kind(&self) -> &PatternErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            PatternErrorKind::UnclosedPlaceholder => f.write_str("unclosed placeholder")?,
+            PatternErrorKind::UnmatchedBrace => {
+                f.write_str("unmatched `}`, literal braces are written `}}`")?
+            }
+            PatternErrorKind::UnknownSelector(selector) => write!(
+                f,
+                "unknown selector \"{selector}\", expected `first`, `rest` or `all`"
+            )?,
+            PatternErrorKind::MissingWordCase => f.write_str("missing `:` and word casing")?,
+            PatternErrorKind::UnknownWordCase(word_case) => write!(
+                f,
+                "unknown word casing \"{word_case}\", expected `lower`, `UPPER` or `Title`"
+            )?,
+            PatternErrorKind::UnexpectedPlaceholder => {
+                f.write_str("unexpected placeholder, expected `{all:..}` or `{first:..}{rest:..}`")?
+            }
+            PatternErrorKind::UnexpectedLiteral => {
+                f.write_str("unexpected text between placeholders")?
+            }
+            PatternErrorKind::EmptySeparator => {
+                f.write_str("missing separator after `|`, a brace is written `\\}` in a separator")?
+            }
+            PatternErrorKind::ConflictingSeparators => {
+                f.write_str("separator differs from the one of `first`")?
+            }
+            PatternErrorKind::MissingPlaceholder => {
+                f.write_str("missing placeholder, expected `{all:..}` or `{first:..}{rest:..}`")?
+            }
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl Error for PatternError {}
+
+#[cfg(test)]
+mod pattern_tests {
+    use crate::{CustomCase, PatternErrorKind, ReCase};
+
+    #[test]
+    fn test_patterns() {
+        let recase = ReCase::new("göd mätsuri example");
+        let input = [
+            ("{all:lower|_}", "göd_mätsuri_example"),
+            ("{all:UPPER|-}", "GÖD-MÄTSURI-EXAMPLE"),
+            ("{all:title}", "GödMätsuriExample"),
+            ("{first:lower}{rest:Title}", "gödMätsuriExample"),
+            ("{first:Title|__}{rest:lower}", "Göd__mätsuri__example"),
+            ("{first:lower|.}{rest:UPPER|.}", "göd.MÄTSURI.EXAMPLE"),
+            ("m_{all:lower|_}", "m_göd_mätsuri_example"),
+            ("{{{all:lower| }}}", "{göd mätsuri example}"),
+            ("I{all:Title}Ext", "IGödMätsuriExampleExt"),
+            ("{all:lower|\\}}", "göd}mätsuri}example"),
+            ("{all:lower|\\|}", "göd|mätsuri|example"),
+            ("{all:lower|||}", "göd||mätsuri||example"),
+            ("{all:lower|\\\\}", "göd\\mätsuri\\example"),
+            ("{all:lower|\\}}}}", "göd}mätsuri}example}"),
+        ];
+
+        for (pattern, expected) in input {
+            let case: CustomCase = pattern.parse().unwrap();
+            assert_eq!(recase.custom_case(&case), expected, "{pattern}");
+        }
+    }
+
+    #[test]
+    fn test_pattern_errors() {
+        let input = [
+            ("{all:lower", 0, PatternErrorKind::UnclosedPlaceholder),
+            ("m_}{all:lower}", 2, PatternErrorKind::UnmatchedBrace),
+            (
+                "{each:lower}",
+                1,
+                PatternErrorKind::UnknownSelector(String::from("each")),
+            ),
+            ("m_{all}", 6, PatternErrorKind::MissingWordCase),
+            (
+                "{all:camel|_}",
+                5,
+                PatternErrorKind::UnknownWordCase(String::from("camel")),
+            ),
+            ("{rest:lower}", 0, PatternErrorKind::UnexpectedPlaceholder),
+            (
+                "{all:lower}{rest:lower}",
+                11,
+                PatternErrorKind::UnexpectedPlaceholder,
+            ),
+            (
+                "{first:lower}_{rest:Title}",
+                13,
+                PatternErrorKind::UnexpectedLiteral,
+            ),
+            (
+                "{first:lower|_}{rest:lower|-}",
+                15,
+                PatternErrorKind::ConflictingSeparators,
+            ),
+            ("{first:lower}", 13, PatternErrorKind::MissingPlaceholder),
+            ("m_", 2, PatternErrorKind::MissingPlaceholder),
+            ("{all:lower|}}}", 10, PatternErrorKind::EmptySeparator),
+            (
+                "{first:lower|}{rest:Title}",
+                12,
+                PatternErrorKind::EmptySeparator,
+            ),
+            ("{all:lower|\\}", 0, PatternErrorKind::UnclosedPlaceholder),
+            ("{all:lower|\\", 0, PatternErrorKind::UnclosedPlaceholder),
+        ];
+
+        for (pattern, position, kind) in input {
+            let error = pattern.parse::<CustomCase>().unwrap_err();
+            assert_eq!(
+                (error.position(), error.kind()),
+                (position, &kind),
+                "{pattern}"
+            );
+        }
+
+        let error = "{all:camel}".parse::<CustomCase>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unknown word casing \"camel\", expected `lower`, `UPPER` or `Title` at position 5"
+        );
+    }
+}
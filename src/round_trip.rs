@@ -0,0 +1,177 @@
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Words of an input that don't come back the same when the output of a conversion is read back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Loss {
+    /// The byte range of the lost words in the input. It is empty for words that only appear in the output, and
+    /// is located where they were added.
+    pub span: Range<usize>,
+    /// The lost words, lowercased.
+    pub words: Vec<String>,
+    /// The words read back from the output in their place.
+    pub parsed: Vec<String>,
+    /// The graphemes of the lost words that are missing from the words read back, like the `ß` of a word read
+    /// back as `ss`. It is empty when the words were only split or joined differently.
+    pub graphemes: Vec<String>,
+}
+
+/// The result of converting an input into a case and reading the output back with the same options.
+/// # Example
+/// ```
+/// use recase::{Case, ReCase};
+///
+/// let round_trip = ReCase::new("ßlong").round_trip(Case::UpperSnake);
+/// assert_eq!(round_trip.output, String::from("SSLONG"));
+/// assert!(!round_trip.is_lossless());
+/// assert_eq!(round_trip.losses[0].words, vec![String::from("ßlong")]);
+/// assert_eq!(round_trip.losses[0].graphemes, vec![String::from("ß")]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundTrip {
+    /// The input converted into the case.
+    pub output: String,
+    /// The words that don't survive the conversion, in order.
+    pub losses: Vec<Loss>,
+}
+
+impl RoundTrip {
+    /// Returns true if reading the output back returns the words of the input.
+    pub fn is_lossless(&self) -> bool {
+        self.losses.is_empty()
+    }
+}
+
+/// Aligns the words of an input with the words read back from its output, returning the runs of words that
+/// differ between the longest common subsequences of both.
+pub(crate) fn losses(spans: &[Range<usize>], words: &[String], parsed: &[String]) -> Vec<Loss> {
+    // lengths[i][j] is the length of the longest common subsequence of words[i..] and parsed[j..]
+    let mut lengths = vec![vec![0; parsed.len() + 1]; words.len() + 1];
+    for i in (0..words.len()).rev() {
+        for j in (0..parsed.len()).rev() {
+            lengths[i][j] = if words[i] == parsed[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut losses = vec![];
+    let (mut i, mut j) = (0, 0);
+    let (mut lost_start, mut parsed_start) = (0, 0);
+    loop {
+        let is_end = i == words.len() && j == parsed.len();
+        let is_match = i < words.len() && j < parsed.len() && words[i] == parsed[j];
+        if is_end || is_match {
+            if lost_start < i || parsed_start < j {
+                let span = match spans.get(lost_start..i) {
+                    Some([first, .., last]) => first.start..last.end,
+                    Some([only]) => only.clone(),
+                    _ => {
+                        let position = spans.get(i).map_or_else(
+                            || spans.last().map_or(0, |last| last.end),
+                            |span| span.start,
+                        );
+                        position..position
+                    }
+                };
+                losses.push(loss(span, &words[lost_start..i], &parsed[parsed_start..j]));
+            }
+            if is_end {
+                break;
+            }
+            i += 1;
+            j += 1;
+            (lost_start, parsed_start) = (i, j);
+        } else if j == parsed.len() || (i < words.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    losses
+}
+
+fn loss(span: Range<usize>, words: &[String], parsed: &[String]) -> Loss {
+    let mut remaining: Vec<&str> = parsed
+        .iter()
+        .flat_map(|word| word.graphemes(true))
+        .collect();
+    let graphemes = words
+        .iter()
+        .flat_map(|word| word.graphemes(true))
+        .filter(|g| match remaining.iter().position(|r| r == g) {
+            Some(position) => {
+                remaining.swap_remove(position);
+                false
+            }
+            None => true,
+        })
+        .map(str::to_owned)
+        .collect();
+
+    Loss {
+        span,
+        words: words.to_vec(),
+        parsed: parsed.to_vec(),
+        graphemes,
+    }
+}
+
+#[cfg(test)]
+mod round_trip_tests {
+    use crate::{Case, Options, ReCase, Segmentation};
+
+    #[test]
+    fn test_lossless_round_trips() {
+        let options = Options::new().segmentation(Segmentation::Acronym);
+        let recase = ReCase::with_options("example_string", options);
+        for case in Case::ALL {
+            let round_trip = recase.round_trip(case);
+            let lossy = matches!(case, Case::Flat | Case::UpperFlat | Case::Alternating);
+            assert_eq!(
+                round_trip.is_lossless(),
+                !lossy,
+                "{case}: {:?}",
+                round_trip.losses
+            );
+            assert_eq!(round_trip.output, recase.to_case(case));
+        }
+        assert!(ReCase::new("").round_trip(Case::Snake).is_lossless());
+    }
+
+    #[test]
+    fn test_losses() {
+        let recase = ReCase::new("long_word");
+        let round_trip = recase.round_trip(Case::UpperSnake);
+        assert_eq!(round_trip.losses.len(), 1);
+        assert_eq!(round_trip.losses[0].span, 0..9);
+        assert_eq!(round_trip.losses[0].words, vec!["long", "word"]);
+        assert_eq!(
+            round_trip.losses[0].parsed,
+            vec!["l", "o", "n", "g", "w", "o", "r", "d"]
+        );
+        assert!(round_trip.losses[0].graphemes.is_empty());
+        assert!(recase.round_trip(Case::Snake).is_lossless());
+
+        let options = Options::new().segmentation(Segmentation::Acronym);
+        let round_trip =
+            ReCase::with_options("my ßlong word", options).round_trip(Case::UpperSnake);
+        assert_eq!(round_trip.output, "MY_SSLONG_WORD");
+        assert_eq!(round_trip.losses.len(), 1);
+        let loss = &round_trip.losses[0];
+        assert_eq!(loss.span, 3..9);
+        assert_eq!(loss.words, vec!["ßlong"]);
+        assert_eq!(loss.parsed, vec!["sslong"]);
+        assert_eq!(loss.graphemes, vec!["ß"]);
+
+        let round_trip = ReCase::new("foo bar baz").round_trip(Case::Flat);
+        assert_eq!(round_trip.losses.len(), 1);
+        assert_eq!(round_trip.losses[0].span, 0..11);
+        assert_eq!(round_trip.losses[0].words, vec!["foo", "bar", "baz"]);
+        assert_eq!(round_trip.losses[0].parsed, vec!["foobarbaz"]);
+    }
+}
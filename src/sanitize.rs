@@ -0,0 +1,336 @@
+use unicode_ident::{is_xid_continue, is_xid_start};
+
+/// A programming language whose identifier rules and keywords are known to the [`Sanitizer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    Rust,
+    Python,
+    JavaScript,
+    TypeScript,
+    Go,
+    Java,
+    CSharp,
+    Sql,
+}
+
+impl Language {
+    /// Returns the reserved words of the language.
+    pub fn keywords(self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => RUST_KEYWORDS,
+            Language::Python => PYTHON_KEYWORDS,
+            Language::JavaScript => JAVASCRIPT_KEYWORDS,
+            Language::TypeScript => TYPESCRIPT_KEYWORDS,
+            Language::Go => GO_KEYWORDS,
+            Language::Java => JAVA_KEYWORDS,
+            Language::CSharp => CSHARP_KEYWORDS,
+            Language::Sql => SQL_KEYWORDS,
+        }
+    }
+
+    /// Returns true if the word is reserved in the language. SQL keywords are matched case-insensitively.
+    /// ## Example
+    /// ```
+    /// use recase::Language;
+    /// assert!(Language::Rust.is_keyword("async"));
+    /// assert!(Language::Sql.is_keyword("Select"));
+    /// assert!(!Language::Python.is_keyword("self"));
+    /// ```
+    pub fn is_keyword(self, word: &str) -> bool {
+        match self {
+            Language::Sql => self.keywords().iter().any(|k| k.eq_ignore_ascii_case(word)),
+            _ => self.keywords().contains(&word),
+        }
+    }
+
+    fn is_identifier_start(self, c: char) -> bool {
+        is_xid_start(c) || c == '_' || (c == '$' && self.allows_dollar())
+    }
+
+    fn is_identifier_continue(self, c: char) -> bool {
+        is_xid_continue(c) || (c == '$' && self.allows_dollar())
+    }
+
+    fn allows_dollar(self) -> bool {
+        matches!(
+            self,
+            Language::JavaScript | Language::TypeScript | Language::Java
+        )
+    }
+}
+
+/// Controls how an identifier that is a keyword is escaped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeywordEscape {
+    /// The escape of the language itself: `r#type` in Rust, `@class` in C# and `"select"` in SQL.
+    /// Languages without one, and Rust keywords that can't be raw identifiers (`self`, `Self`, `super`, `crate`),
+    /// use a suffix instead.
+    #[default]
+    Native,
+    /// An underscore is appended, e.g. `type_`.
+    Suffix,
+}
+
+/// Turns the outputs of a [`ReCase`](crate::ReCase) into valid identifiers of a language. Characters that can't be
+/// part of an identifier are removed, an underscore is prepended to identifiers that can't start with their first
+/// character (e.g. a digit), and keywords are escaped. Only outputs that form identifiers are sanitized:
+/// `dot.case` outputs are treated as qualified names and sanitized part by part, while outputs separated by
+/// other characters, like `normal case`, `Title Case`, `path/case` or `kebab-case`, are left as they are. An
+/// output without any identifier character stays empty, leaving the naming of empty inputs to the caller.
+/// # Example
+/// ```
+/// use recase::{Language, Options, ReCase, Sanitizer};
+///
+/// let options = Options::new().sanitizer(Sanitizer::new(Language::Rust));
+/// assert_eq!(ReCase::with_options("Type", options.clone()).snake_case(), String::from("r#type"));
+/// assert_eq!(ReCase::with_options("2fa code", options).snake_case(), String::from("_2fa_code"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sanitizer {
+    language: Language,
+    keyword_escape: KeywordEscape,
+}
+
+impl Sanitizer {
+    /// Create a new Sanitizer for the language, escaping keywords with [`KeywordEscape::Native`].
+    pub fn new(language: Language) -> Sanitizer {
+        Sanitizer {
+            language,
+            keyword_escape: KeywordEscape::default(),
+        }
+    }
+
+    /// Sets how keywords are escaped.
+    pub fn keyword_escape(mut self, keyword_escape: KeywordEscape) -> Sanitizer {
+        self.keyword_escape = keyword_escape;
+        self
+    }
+
+    /// Returns the language of the Sanitizer.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Returns a valid identifier made from the input, or an empty string if the input has no identifier
+    /// character, since no identifier can be made from it.
+    /// ## Example
+    /// ```
+    /// use recase::{KeywordEscape, Language, Sanitizer};
+    ///
+    /// let sanitizer = Sanitizer::new(Language::Rust).keyword_escape(KeywordEscape::Suffix);
+    /// assert_eq!(sanitizer.sanitize("type"), String::from("type_"));
+    /// assert_eq!(Sanitizer::new(Language::CSharp).sanitize("class"), String::from("@class"));
+    /// assert_eq!(Sanitizer::new(Language::Python).sanitize("döner-kebab?"), String::from("dönerkebab"));
+    /// ```
+    pub fn sanitize(&self, identifier: &str) -> String {
+        let language = self.language;
+        let mut res: String = identifier
+            .chars()
+            .filter(|&c| language.is_identifier_continue(c))
+            .collect();
+
+        match res.chars().next() {
+            None => return res,
+            Some(c) if !language.is_identifier_start(c) => res.insert(0, '_'),
+            Some(_) => {}
+        }
+
+        if language.is_keyword(&res) {
+            res = self.escape_keyword(res);
+        }
+
+        res
+    }
+
+    /// Returns true if every character of the text can be part of an identifier.
+    pub(crate) fn is_identifier(&self, text: &str) -> bool {
+        text.chars()
+            .all(|c| self.language.is_identifier_continue(c))
+    }
+
+    fn escape_keyword(&self, mut keyword: String) -> String {
+        match (self.keyword_escape, self.language) {
+            (KeywordEscape::Native, Language::Rust)
+                if !RUST_NON_RAW_KEYWORDS.contains(&keyword.as_str()) =>
+            {
+                keyword.insert_str(0, "r#")
+            }
+            (KeywordEscape::Native, Language::CSharp) => keyword.insert(0, '@'),
+            (KeywordEscape::Native, Language::Sql) => {
+                keyword.insert(0, '"');
+                keyword.push('"');
+            }
+            _ => keyword.push('_'),
+        }
+        keyword
+    }
+}
+
+const RUST_NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+#[rustfmt::skip]
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final", "gen", "macro",
+    "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+#[rustfmt::skip]
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+#[rustfmt::skip]
+const JAVASCRIPT_KEYWORDS: &[&str] = &[
+    "await", "break", "case", "catch", "class", "const", "continue", "debugger", "default",
+    "delete", "do", "else", "enum", "export", "extends", "false", "finally", "for", "function",
+    "if", "implements", "import", "in", "instanceof", "interface", "let", "new", "null", "package",
+    "private", "protected", "public", "return", "static", "super", "switch", "this", "throw",
+    "true", "try", "typeof", "var", "void", "while", "with", "yield", "arguments", "eval",
+];
+
+#[rustfmt::skip]
+const TYPESCRIPT_KEYWORDS: &[&str] = &[
+    "await", "break", "case", "catch", "class", "const", "continue", "debugger", "default",
+    "delete", "do", "else", "enum", "export", "extends", "false", "finally", "for", "function",
+    "if", "implements", "import", "in", "instanceof", "interface", "let", "new", "null", "package",
+    "private", "protected", "public", "return", "static", "super", "switch", "this", "throw",
+    "true", "try", "typeof", "var", "void", "while", "with", "yield", "arguments", "eval", "any",
+    "boolean", "declare", "never", "number", "object", "string", "symbol", "undefined", "unknown",
+];
+
+#[rustfmt::skip]
+const GO_KEYWORDS: &[&str] = &[
+    "break", "case", "chan", "const", "continue", "default", "defer", "else", "fallthrough", "for",
+    "func", "go", "goto", "if", "import", "interface", "map", "package", "range", "return",
+    "select", "struct", "switch", "type", "var",
+];
+
+#[rustfmt::skip]
+const JAVA_KEYWORDS: &[&str] = &[
+    "abstract", "assert", "boolean", "break", "byte", "case", "catch", "char", "class", "const",
+    "continue", "default", "do", "double", "else", "enum", "extends", "final", "finally", "float",
+    "for", "goto", "if", "implements", "import", "instanceof", "int", "interface", "long", "native",
+    "new", "package", "private", "protected", "public", "return", "short", "static", "strictfp",
+    "super", "switch", "synchronized", "this", "throw", "throws", "transient", "try", "void",
+    "volatile", "while", "true", "false", "null", "var", "yield", "record",
+];
+
+#[rustfmt::skip]
+const CSHARP_KEYWORDS: &[&str] = &[
+    "abstract", "as", "base", "bool", "break", "byte", "case", "catch", "char", "checked", "class",
+    "const", "continue", "decimal", "default", "delegate", "do", "double", "else", "enum", "event",
+    "explicit", "extern", "false", "finally", "fixed", "float", "for", "foreach", "goto", "if",
+    "implicit", "in", "int", "interface", "internal", "is", "lock", "long", "namespace", "new",
+    "null", "object", "operator", "out", "override", "params", "private", "protected", "public",
+    "readonly", "ref", "return", "sbyte", "sealed", "short", "sizeof", "stackalloc", "static",
+    "string", "struct", "switch", "this", "throw", "true", "try", "typeof", "uint", "ulong",
+    "unchecked", "unsafe", "ushort", "using", "virtual", "void", "volatile", "while",
+];
+
+#[rustfmt::skip]
+const SQL_KEYWORDS: &[&str] = &[
+    "add", "all", "alter", "and", "any", "as", "asc", "between", "by", "case", "cast", "check",
+    "column", "constraint", "create", "cross", "current_date", "current_time", "current_timestamp",
+    "current_user", "database", "default", "delete", "desc", "distinct", "drop", "else", "end",
+    "escape", "except", "exists", "false", "fetch", "for", "foreign", "from", "full", "grant",
+    "group", "having", "in", "index", "inner", "insert", "intersect", "into", "is", "join", "key",
+    "left", "like", "limit", "natural", "not", "null", "offset", "on", "or", "order", "outer",
+    "primary", "references", "revoke", "right", "row", "rows", "select", "session_user", "set",
+    "some", "table", "then", "to", "true", "union", "unique", "update", "user", "using", "values",
+    "view", "when", "where", "with",
+];
+
+#[cfg(test)]
+mod sanitize_tests {
+    use crate::{KeywordEscape, Language, Options, ReCase, Sanitizer};
+
+    #[test]
+    fn test_keywords() {
+        let input = [
+            (Language::Rust, "type", "r#type"),
+            (Language::Rust, "self", "self_"),
+            (Language::Rust, "Self", "Self_"),
+            (Language::Rust, "Type", "Type"),
+            (Language::Python, "class", "class_"),
+            (Language::JavaScript, "delete", "delete_"),
+            (Language::TypeScript, "string", "string_"),
+            (Language::Go, "func", "func_"),
+            (Language::Java, "package", "package_"),
+            (Language::CSharp, "class", "@class"),
+            (Language::Sql, "ORDER", "\"ORDER\""),
+        ];
+
+        for (language, identifier, expected) in input {
+            assert_eq!(Sanitizer::new(language).sanitize(identifier), expected);
+        }
+
+        let sanitizer = Sanitizer::new(Language::Sql).keyword_escape(KeywordEscape::Suffix);
+        assert_eq!(sanitizer.sanitize("select"), "select_");
+    }
+
+    #[test]
+    fn test_identifier_characters() {
+        let sanitizer = Sanitizer::new(Language::Rust);
+        assert_eq!(sanitizer.sanitize("2fa_code"), "_2fa_code");
+        assert_eq!(sanitizer.sanitize("matsuri?"), "matsuri");
+        assert_eq!(sanitizer.sanitize("まつり"), "まつり");
+        assert_eq!(sanitizer.sanitize("?!"), "");
+        assert_eq!(sanitizer.sanitize(""), "");
+        assert_eq!(sanitizer.sanitize("$scope"), "scope");
+
+        let sanitizer = Sanitizer::new(Language::JavaScript);
+        assert_eq!(sanitizer.sanitize("$scope"), "$scope");
+        assert_eq!(sanitizer.sanitize("٣d"), "_٣d");
+    }
+
+    #[test]
+    fn test_sanitized_outputs() {
+        let options = Options::new().sanitizer(Sanitizer::new(Language::Rust));
+
+        for input in ["", "__", "?!", "-?-"] {
+            let recase = ReCase::with_options(input, options.clone());
+            assert_eq!(recase.snake_case(), "", "{input}");
+            assert_eq!(recase.pascal_case(), "", "{input}");
+            assert_eq!(recase.dot_case(), "", "{input}");
+        }
+        assert_eq!(ReCase::with_options("", Options::rust()).snake_case(), "");
+
+        let recase = ReCase::with_options("async", options.clone());
+        assert_eq!(recase.snake_case(), "r#async");
+        assert_eq!(recase.pascal_case(), "Async");
+
+        let recase = ReCase::with_options("2fa code", options.clone());
+        assert_eq!(recase.snake_case(), "_2fa_code");
+        assert_eq!(recase.upper_snake_case(), "_2FA_CODE");
+        assert_eq!(recase.kebab_case(), "2fa-code");
+        assert_eq!(recase.pascal_case(), "_2faCode");
+
+        let recase = ReCase::with_options("crate.type", options.clone());
+        assert_eq!(recase.dot_case(), "crate_.r#type");
+        assert_eq!(recase.snake_case(), "crate_type");
+
+        let recase = ReCase::with_options("crate type", options.clone());
+        assert_eq!(recase.kebab_case(), "crate-type");
+        assert_eq!(recase.header_case(), "Crate-Type");
+        assert_eq!(recase.path_case(), "crate/type");
+        assert_eq!(recase.dot_case(), "crate_.r#type");
+
+        let options = Options::new().sanitizer(Sanitizer::new(Language::Python));
+        let recase = ReCase::with_options("Class", options.clone());
+        assert_eq!(recase.snake_case(), "class_");
+        assert_eq!(recase.pascal_case(), "Class");
+
+        let recase = ReCase::with_options("is class", options);
+        assert_eq!(recase.normal_case(), "is class");
+        assert_eq!(recase.sentence_case(), "Is class");
+        assert_eq!(recase.title_case(), "Is Class");
+        assert_eq!(recase.snake_case(), "is_class");
+        assert_eq!(recase.dot_case(), "is_.class_");
+    }
+}
@@ -0,0 +1,141 @@
+use std::ops::Range;
+
+use crate::{utils, Options};
+
+/// Splits an input text into words.
+///
+/// A segmenter returns the byte ranges of the words found in the input. `ReCase` sorts them and clamps them to
+/// the input; empty ranges, ranges that don't lie on `char` boundaries and ranges overlapping a previous one are
+/// dropped. The words are lowercased by `ReCase` before any case is applied, so a segmenter only decides where
+/// words start and end.
+///
+/// Any `Fn(&str) -> Vec<Range<usize>>` closure is a segmenter.
+/// # Example
+/// ```
+/// use recase::ReCase;
+///
+/// // splits only on `::`
+/// let segmenter = |input: &str| {
+///     let mut spans = vec![];
+///     let mut start = 0;
+///     for (i, _) in input.match_indices("::") {
+///         spans.push(start..i);
+///         start = i + 2;
+///     }
+///     spans.push(start..input.len());
+///     spans
+/// };
+/// let recase = ReCase::with_segmenter("std::io_error", segmenter);
+/// assert_eq!(recase.pascal_case(), String::from("StdIo_error"));
+/// ```
+pub trait Segmenter: Send + Sync {
+    /// Returns the byte ranges of the words in `input`.
+    fn segment(&self, input: &str) -> Vec<Range<usize>>;
+}
+
+impl<F> Segmenter for F
+where
+    F: Fn(&str) -> Vec<Range<usize>> + Send + Sync,
+{
+    fn segment(&self, input: &str) -> Vec<Range<usize>> {
+        self(input)
+    }
+}
+
+/// The segmenter used by `ReCase` unless another one is given. It splits words on separators, uppercase
+/// letters and letter/digit transitions as configured by the [`Options`] it is created with.
+/// It can be used by other segmenters to refine its result.
+/// # Example
+/// ```
+/// use recase::{DefaultSegmenter, Options, Segmenter};
+///
+/// let segmenter = DefaultSegmenter::new(Options::new());
+/// assert_eq!(segmenter.segment("godMatsuri"), vec![0..3, 3..10]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DefaultSegmenter {
+    options: Options,
+}
+
+impl DefaultSegmenter {
+    /// Create a new DefaultSegmenter. Any segmenter set on the options is ignored.
+    pub fn new(options: Options) -> DefaultSegmenter {
+        DefaultSegmenter { options }
+    }
+}
+
+impl Segmenter for DefaultSegmenter {
+    fn segment(&self, input: &str) -> Vec<Range<usize>> {
+        utils::slice_into_spans(input, &self.options)
+    }
+}
+
+#[cfg(test)]
+mod segmenter_tests {
+    use std::ops::Range;
+
+    use crate::{DefaultSegmenter, Options, ReCase, Segmentation, Segmenter};
+
+    #[test]
+    fn test_default_segmenter() {
+        let segmenter = DefaultSegmenter::new(Options::new());
+        assert_eq!(segmenter.segment("_god matsuri_"), vec![1..4, 5..12]);
+        assert_eq!(segmenter.segment("ĞodMatsu"), vec![0..4, 4..9]);
+        assert_eq!(segmenter.segment(""), vec![]);
+
+        let segmenter = DefaultSegmenter::new(Options::new().segmentation(Segmentation::Acronym));
+        assert_eq!(segmenter.segment("HTTPServer"), vec![0..4, 4..10]);
+    }
+
+    #[test]
+    fn test_custom_segmenter() {
+        // a dictionary-based segmenter on top of the default one
+        let segmenter = |input: &str| {
+            DefaultSegmenter::new(Options::new())
+                .segment(input)
+                .into_iter()
+                .flat_map(|span| match &input[span.clone()] {
+                    "userid" => vec![span.start..span.start + 4, span.start + 4..span.end],
+                    _ => vec![span],
+                })
+                .collect()
+        };
+        let recase = ReCase::with_segmenter("get userid", segmenter);
+        assert_eq!(recase.snake_case(), "get_user_id");
+        assert_eq!(recase.camel_case(), "getUserId");
+
+        let recase = ReCase::with_segmenter("no words", |_: &str| vec![0..0, 3..3]);
+        assert_eq!(recase.snake_case(), "");
+    }
+
+    #[test]
+    fn test_invalid_spans() {
+        let input = "hello world";
+
+        // out of bounds
+        let recase = ReCase::with_segmenter(input, |_: &str| vec![0..5, 6..20, 30..40]);
+        assert_eq!(recase.snake_case(), "hello_world");
+
+        // not on char boundaries
+        let recase =
+            ReCase::with_segmenter("héllo wörld", |_: &str| vec![0..2, 0..6, 7..9, 7..13]);
+        assert_eq!(recase.snake_case(), "héllo_wörld");
+
+        // out of order
+        let recase = ReCase::with_segmenter(input, |_: &str| vec![6..11, 0..5]);
+        assert_eq!(recase.snake_case(), "hello_world");
+        assert_eq!(recase.title_case(), "Hello World");
+
+        // overlapping, where the span starting first is kept
+        let recase = ReCase::with_segmenter(input, |_: &str| vec![0..5, 3..8, 6..11]);
+        assert_eq!(recase.snake_case(), "hello_world");
+        assert_eq!(recase.title_case(), "Hello World");
+        let recase = ReCase::with_segmenter(input, |_: &str| vec![0..8, 6..11]);
+        assert_eq!(recase.title_case(), "Hello wo");
+
+        // reversed
+        let recase =
+            ReCase::with_segmenter(input, |_: &str| vec![Range { start: 5, end: 0 }, 6..11]);
+        assert_eq!(recase.snake_case(), "world");
+    }
+}
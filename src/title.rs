@@ -0,0 +1,185 @@
+use crate::utils;
+
+/// A style guide for `Title Case`, deciding which minor words (articles, conjunctions and prepositions) stay
+/// lowercase. In every guide, the first and last words and the word after a colon are capitalized, and words joined
+/// by a hyphen in the input stay hyphenated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TitleStyle {
+    /// Associated Press: articles, conjunctions and prepositions of up to three letters are lowercase.
+    Ap,
+    /// American Psychological Association: articles, conjunctions and prepositions of up to three letters are
+    /// lowercase.
+    Apa,
+    /// Chicago Manual of Style: articles, prepositions of any length, `and`, `but`, `for`, `or`, `nor`, `to` and `as`
+    /// are lowercase. The part of a compound after a prefix such as `anti` or `pre` is lowercase too.
+    Chicago,
+    /// Modern Language Association: articles, prepositions of any length, coordinating conjunctions and `to` are
+    /// lowercase.
+    Mla,
+    /// The New York Times: `a`, `and`, `as`, `at`, `but`, `by`, `en`, `for`, `if`, `in`, `of`, `on`, `or`, `the`,
+    /// `to`, `v`, `via` and `vs` are lowercase.
+    Nyt,
+    /// Wikipedia's Manual of Style: articles, coordinating conjunctions and prepositions of up to four letters are
+    /// lowercase.
+    Wikipedia,
+}
+
+impl TitleStyle {
+    /// Returns the lowercase words of the style.
+    pub fn minor_words(self) -> &'static [&'static str] {
+        match self {
+            TitleStyle::Ap | TitleStyle::Apa => SHORT_MINOR_WORDS,
+            TitleStyle::Chicago => CHICAGO_MINOR_WORDS,
+            TitleStyle::Mla => MLA_MINOR_WORDS,
+            TitleStyle::Nyt => NYT_MINOR_WORDS,
+            TitleStyle::Wikipedia => WIKIPEDIA_MINOR_WORDS,
+        }
+    }
+
+    /// Returns true if the word stays lowercase inside a title, ignoring the punctuation around it.
+    /// ## Example
+    /// ```
+    /// use recase::TitleStyle;
+    /// assert!(TitleStyle::Chicago.is_minor_word("between"));
+    /// assert!(!TitleStyle::Ap.is_minor_word("between"));
+    /// assert!(TitleStyle::Ap.is_minor_word("of"));
+    /// ```
+    pub fn is_minor_word(self, word: &str) -> bool {
+        utils::is_minor_word(self.minor_words(), word)
+    }
+
+    /// Returns true if the part of a compound after the given part is lowercase, as in `Anti-inflammatory`.
+    pub(crate) fn lowercases_after(self, part: &str) -> bool {
+        self == TitleStyle::Chicago && CHICAGO_PREFIXES.contains(&part)
+    }
+}
+
+#[rustfmt::skip]
+const SHORT_MINOR_WORDS: &[&str] = &[
+    "a", "an", "the",
+    "and", "but", "for", "nor", "or", "so", "yet",
+    "as", "at", "by", "in", "of", "off", "on", "out", "per", "to", "up", "via",
+];
+
+#[rustfmt::skip]
+const CHICAGO_MINOR_WORDS: &[&str] = &[
+    "a", "an", "the",
+    "and", "but", "for", "nor", "or",
+    "to", "as",
+    "about", "above", "across", "after", "against", "along", "among", "around", "at", "before", "behind",
+    "below", "beneath", "beside", "between", "beyond", "by", "despite", "down", "during", "except", "from",
+    "in", "inside", "into", "like", "near", "of", "off", "on", "onto", "out", "outside", "over", "past", "per",
+    "since", "through", "throughout", "toward", "towards", "under", "underneath", "until", "up", "upon", "via",
+    "with", "within", "without",
+];
+
+#[rustfmt::skip]
+const MLA_MINOR_WORDS: &[&str] = &[
+    "a", "an", "the",
+    "and", "but", "for", "nor", "or", "so", "yet",
+    "to",
+    "about", "above", "across", "after", "against", "along", "among", "around", "as", "at", "before", "behind",
+    "below", "beneath", "beside", "between", "beyond", "by", "despite", "down", "during", "except", "from",
+    "in", "inside", "into", "like", "near", "of", "off", "on", "onto", "out", "outside", "over", "past", "per",
+    "since", "through", "throughout", "toward", "towards", "under", "underneath", "until", "up", "upon", "via",
+    "with", "within", "without",
+];
+
+#[rustfmt::skip]
+const NYT_MINOR_WORDS: &[&str] = &[
+    "a", "and", "as", "at", "but", "by", "en", "for", "if", "in", "of", "on", "or", "the", "to", "v", "via", "vs",
+];
+
+#[rustfmt::skip]
+const WIKIPEDIA_MINOR_WORDS: &[&str] = &[
+    "a", "an", "the",
+    "and", "but", "for", "nor", "or", "so", "yet",
+    "as", "at", "by", "from", "in", "into", "like", "near", "of", "off", "on", "onto", "out", "over", "past",
+    "per", "to", "up", "upon", "via", "with",
+];
+
+#[rustfmt::skip]
+const CHICAGO_PREFIXES: &[&str] = &[
+    "anti", "co", "counter", "de", "extra", "inter", "intra", "macro", "micro", "mid", "mini", "multi", "non",
+    "post", "pre", "pro", "pseudo", "re", "semi", "sub", "super", "trans", "ultra", "un",
+];
+
+#[cfg(test)]
+mod title_tests {
+    use crate::{Casing, ReCase, TitleStyle};
+
+    #[test]
+    fn test_title_styles() {
+        let input = [
+            (
+                "the lord of the rings",
+                TitleStyle::Ap,
+                "The Lord of the Rings",
+            ),
+            (
+                "a tale of two cities",
+                TitleStyle::Chicago,
+                "A Tale of Two Cities",
+            ),
+            (
+                "a walk through the woods",
+                TitleStyle::Ap,
+                "A Walk Through the Woods",
+            ),
+            (
+                "a walk through the woods",
+                TitleStyle::Mla,
+                "A Walk through the Woods",
+            ),
+            ("what we look for", TitleStyle::Chicago, "What We Look For"),
+            (
+                "so you want to dance",
+                TitleStyle::Chicago,
+                "So You Want to Dance",
+            ),
+            (
+                "war and peace is up for grabs",
+                TitleStyle::Nyt,
+                "War and Peace Is Up for Grabs",
+            ),
+            (
+                "life with an idiot",
+                TitleStyle::Wikipedia,
+                "Life with an Idiot",
+            ),
+            ("life with an idiot", TitleStyle::Apa, "Life With an Idiot"),
+            (
+                "star wars: a new hope",
+                TitleStyle::Ap,
+                "Star Wars: A New Hope",
+            ),
+            (
+                "the state-of-the-art guide",
+                TitleStyle::Apa,
+                "The State-of-the-Art Guide",
+            ),
+            (
+                "an anti-inflammatory self-report",
+                TitleStyle::Chicago,
+                "An Anti-inflammatory Self-Report",
+            ),
+            (
+                "an anti-inflammatory self-report",
+                TitleStyle::Mla,
+                "An Anti-Inflammatory Self-Report",
+            ),
+            ("of mice and men", TitleStyle::Nyt, "Of Mice and Men"),
+            ("über die brücke", TitleStyle::Ap, "Über Die Brücke"),
+        ];
+
+        for (s, style, expected) in input {
+            assert_eq!(ReCase::new(s).title_case_with(style), expected, "{style:?}");
+        }
+
+        assert_eq!(
+            "the_lord_of_the_rings".to_title_case_with(TitleStyle::Ap),
+            "The Lord of the Rings"
+        );
+        assert_eq!("".to_title_case_with(TitleStyle::Ap), "");
+    }
+}
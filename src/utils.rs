@@ -1,41 +1,231 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
 use unicode_segmentation::UnicodeSegmentation;
 
-pub fn slice_into_words(input: String) -> Vec<String> {
-    pub const SYMBOLS: [&str; 6] = [" ", ".", "/", "_", "-", "\\"];
+use crate::{DigitBoundary, Options, Segmentation};
 
-    let mut words: Vec<String> = vec![];
-    let mut temp_word: Vec<&str> = vec![];
+#[cfg(test)]
+pub fn slice_into_words(input: &str, options: &Options) -> Vec<String> {
+    segment(input, options)
+        .into_iter()
+        .map(|span| lowercase(&input[span]))
+        .collect()
+}
 
-    let vec_to_lowercase = |vec: &Vec<&str>| {
-        vec.iter()
-            .flat_map(|g| g.chars())
-            .flat_map(|c| c.to_lowercase())
-            .collect()
+/// Returns the non-empty word spans of the input, using the segmenter of the options if there is one.
+/// Spans are clamped to the input and sorted; the ones that are empty, don't lie on `char` boundaries or overlap
+/// a previous span are dropped.
+pub fn segment(input: &str, options: &Options) -> Vec<Range<usize>> {
+    let spans = match &options.segmenter {
+        Some(segmenter) => segmenter.segment(input),
+        None => return slice_into_spans(input, options),
     };
 
-    for c in input.graphemes(true) {
-        // slice when a symbol is detected
-        if SYMBOLS.contains(&c) {
-            if !temp_word.is_empty() {
-                words.push(vec_to_lowercase(&temp_word));
-                temp_word.clear();
+    let mut spans: Vec<Range<usize>> = spans
+        .into_iter()
+        .map(|span| span.start.min(input.len())..span.end.min(input.len()))
+        .filter(|span| {
+            !span.is_empty()
+                && input.is_char_boundary(span.start)
+                && input.is_char_boundary(span.end)
+        })
+        .collect();
+    spans.sort_by_key(|span| (span.start, span.end));
+
+    let mut end = 0;
+    spans.retain(|span| {
+        let is_disjoint = span.start >= end;
+        if is_disjoint {
+            end = span.end;
+        }
+        is_disjoint
+    });
+    spans
+}
+
+pub fn slice_into_spans(input: &str, options: &Options) -> Vec<Range<usize>> {
+    let mut spans: Vec<Range<usize>> = vec![];
+    let mut word_start: Option<usize> = None;
+    let mut skip_until = 0;
+
+    let graphemes: Vec<(usize, &str)> = input.grapheme_indices(true).collect();
+    let chars: Vec<&str> = graphemes.iter().map(|&(_, g)| g).collect();
+
+    for (i, &(offset, c)) in graphemes.iter().enumerate() {
+        // skip the rest of a registered acronym
+        if i < skip_until {
+            continue;
+        }
+        // slice when a separator is detected
+        if options.separators.contains(c) {
+            if let Some(start) = word_start.take() {
+                spans.push(start..offset);
             }
             continue;
         }
-        // slice when an uppercase letter is detected
-        if is_uppercase(c) && !temp_word.is_empty() {
-            words.push(vec_to_lowercase(&temp_word));
-            temp_word.clear();
+        // slice when an uppercase letter or a letter/digit transition is detected
+        let is_boundary = word_start.is_some()
+            && ((is_capital(c) && starts_new_word(&chars, i, options))
+                || is_digit_boundary(chars[i - 1], c, options));
+
+        // a registered acronym found where a word can start is a word on its own
+        if word_start.is_none() || is_boundary {
+            if let Some(end) = match_acronym(&graphemes, i, input, options) {
+                if let Some(start) = word_start.take() {
+                    spans.push(start..offset);
+                }
+                spans.push(offset..grapheme_offset(&graphemes, end, input));
+                skip_until = end;
+                continue;
+            }
+        }
+
+        match word_start {
+            Some(start) if is_boundary => {
+                spans.push(start..offset);
+                word_start = Some(offset);
+            }
+            Some(_) => {}
+            None => word_start = Some(offset),
+        }
+    }
+    if let Some(start) = word_start {
+        spans.push(start..input.len());
+    }
+
+    spans
+}
+
+/// Returns the index of the grapheme following the registered acronym that starts at grapheme `i`, if the
+/// acronym is followed by the end of a word.
+fn match_acronym(
+    graphemes: &[(usize, &str)],
+    i: usize,
+    input: &str,
+    options: &Options,
+) -> Option<usize> {
+    if options.acronyms.is_empty() {
+        return None;
+    }
+    let offset = graphemes[i].0;
+
+    options
+        .acronyms
+        .match_prefix(&input[offset..])
+        .filter_map(|len| {
+            // the acronym must end on a grapheme boundary
+            let end = i + graphemes[i..]
+                .iter()
+                .take_while(|&&(o, _)| o < offset + len)
+                .count();
+            (grapheme_offset(graphemes, end, input) == offset + len).then_some(end)
+        })
+        .find(|&end| ends_acronym(graphemes, end, input, options))
+}
+
+fn ends_acronym(graphemes: &[(usize, &str)], i: usize, input: &str, options: &Options) -> bool {
+    let Some(&(_, c)) = graphemes.get(i) else {
+        return true;
+    };
+
+    if options.separators.contains(c) {
+        true
+    } else if is_digit(c) {
+        matches!(
+            options.digit_boundary,
+            DigitBoundary::BeforeDigits | DigitBoundary::Both
+        )
+    } else if is_capital(c) {
+        // either a capitalized word or another acronym follows
+        graphemes
+            .get(i + 1)
+            .is_some_and(|&(_, next)| is_lowercase(next))
+            || match_acronym(graphemes, i, input, options).is_some()
+    } else {
+        false
+    }
+}
+
+fn grapheme_offset(graphemes: &[(usize, &str)], i: usize, input: &str) -> usize {
+    graphemes.get(i).map_or(input.len(), |&(offset, _)| offset)
+}
+
+pub fn lowercase(word: &str) -> String {
+    word.chars().flat_map(|c| c.to_lowercase()).collect()
+}
+
+fn starts_new_word(graphemes: &[&str], i: usize, options: &Options) -> bool {
+    match options.segmentation {
+        Segmentation::EveryCapital => true,
+        // inside a run of capitals, only the last one (followed by a lowercase letter) starts a word
+        Segmentation::Acronym => {
+            !is_capital(graphemes[i - 1])
+                || graphemes.get(i + 1).is_some_and(|next| is_lowercase(next))
         }
-        temp_word.push(c);
     }
-    if !temp_word.is_empty() {
-        words.push(vec_to_lowercase(&temp_word));
+}
+
+fn is_digit_boundary(prev: &str, current: &str, options: &Options) -> bool {
+    let before_digit = is_letter(prev) && is_digit(current);
+    let after_digit = is_digit(prev) && is_letter(current);
+
+    match options.digit_boundary {
+        DigitBoundary::Never => false,
+        DigitBoundary::BeforeDigits => before_digit,
+        DigitBoundary::AfterDigits => after_digit,
+        DigitBoundary::Both => before_digit || after_digit,
     }
+}
+
+/// Returns true if the grapheme starts with a decimal digit, of the Unicode general category `Nd`.
+pub fn is_digit(grapheme: &str) -> bool {
+    grapheme.chars().next().is_some_and(is_decimal_digit)
+}
 
-    words
+fn is_decimal_digit(c: char) -> bool {
+    c.is_ascii_digit()
+        || DECIMAL_DIGITS
+            .binary_search_by(|&(start, end)| {
+                if end < c {
+                    Ordering::Less
+                } else if start > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
 }
 
+/// The ranges of the Unicode general category `Nd`, as of Unicode 16.0.
+#[rustfmt::skip]
+const DECIMAL_DIGITS: &[(char, char)] = &[
+    ('\u{30}', '\u{39}'), ('\u{660}', '\u{669}'), ('\u{6f0}', '\u{6f9}'), ('\u{7c0}', '\u{7c9}'),
+    ('\u{966}', '\u{96f}'), ('\u{9e6}', '\u{9ef}'), ('\u{a66}', '\u{a6f}'), ('\u{ae6}', '\u{aef}'),
+    ('\u{b66}', '\u{b6f}'), ('\u{be6}', '\u{bef}'), ('\u{c66}', '\u{c6f}'), ('\u{ce6}', '\u{cef}'),
+    ('\u{d66}', '\u{d6f}'), ('\u{de6}', '\u{def}'), ('\u{e50}', '\u{e59}'), ('\u{ed0}', '\u{ed9}'),
+    ('\u{f20}', '\u{f29}'), ('\u{1040}', '\u{1049}'), ('\u{1090}', '\u{1099}'), ('\u{17e0}', '\u{17e9}'),
+    ('\u{1810}', '\u{1819}'), ('\u{1946}', '\u{194f}'), ('\u{19d0}', '\u{19d9}'), ('\u{1a80}', '\u{1a89}'),
+    ('\u{1a90}', '\u{1a99}'), ('\u{1b50}', '\u{1b59}'), ('\u{1bb0}', '\u{1bb9}'), ('\u{1c40}', '\u{1c49}'),
+    ('\u{1c50}', '\u{1c59}'), ('\u{a620}', '\u{a629}'), ('\u{a8d0}', '\u{a8d9}'), ('\u{a900}', '\u{a909}'),
+    ('\u{a9d0}', '\u{a9d9}'), ('\u{a9f0}', '\u{a9f9}'), ('\u{aa50}', '\u{aa59}'), ('\u{abf0}', '\u{abf9}'),
+    ('\u{ff10}', '\u{ff19}'), ('\u{104a0}', '\u{104a9}'), ('\u{10d30}', '\u{10d39}'), ('\u{10d40}', '\u{10d49}'),
+    ('\u{11066}', '\u{1106f}'), ('\u{110f0}', '\u{110f9}'), ('\u{11136}', '\u{1113f}'), ('\u{111d0}', '\u{111d9}'),
+    ('\u{112f0}', '\u{112f9}'), ('\u{11450}', '\u{11459}'), ('\u{114d0}', '\u{114d9}'), ('\u{11650}', '\u{11659}'),
+    ('\u{116c0}', '\u{116c9}'), ('\u{116d0}', '\u{116e3}'), ('\u{11730}', '\u{11739}'), ('\u{118e0}', '\u{118e9}'),
+    ('\u{11950}', '\u{11959}'), ('\u{11bf0}', '\u{11bf9}'), ('\u{11c50}', '\u{11c59}'), ('\u{11d50}', '\u{11d59}'),
+    ('\u{11da0}', '\u{11da9}'), ('\u{11f50}', '\u{11f59}'), ('\u{16130}', '\u{16139}'), ('\u{16a60}', '\u{16a69}'),
+    ('\u{16ac0}', '\u{16ac9}'), ('\u{16b50}', '\u{16b59}'), ('\u{16d70}', '\u{16d79}'), ('\u{1ccf0}', '\u{1ccf9}'),
+    ('\u{1d7ce}', '\u{1d7ff}'), ('\u{1e140}', '\u{1e149}'), ('\u{1e2f0}', '\u{1e2f9}'), ('\u{1e4f0}', '\u{1e4f9}'),
+    ('\u{1e5f1}', '\u{1e5fa}'), ('\u{1e950}', '\u{1e959}'), ('\u{1fbf0}', '\u{1fbf9}'),
+];
+
+pub fn is_letter(grapheme: &str) -> bool {
+    grapheme.chars().next().is_some_and(char::is_alphabetic)
+}
+
+#[cfg(test)]
 pub fn is_uppercase(character: &str) -> bool {
     let len = character.graphemes(true).count();
     if len != 1 {
@@ -44,18 +234,102 @@ pub fn is_uppercase(character: &str) -> bool {
     character == character.to_uppercase() && character != character.to_lowercase()
 }
 
-pub fn uppercase_first_letter(word: &str) -> String {
+/// Returns true if the word is one of the minor words of a title, ignoring its case and the punctuation around it.
+pub fn is_minor_word(minor_words: &[&str], word: &str) -> bool {
+    let word = word
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase();
+    minor_words.contains(&word.as_str())
+}
+
+/// Returns true if the grapheme starts with an uppercase or titlecase letter, like `A` or the `ǅ` digraph.
+pub fn is_capital(grapheme: &str) -> bool {
+    grapheme
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() && !c.to_lowercase().eq([c]))
+}
+
+pub fn is_single_letter(word: &str) -> bool {
+    let mut graphemes = word.graphemes(true);
+    graphemes.next().is_some_and(is_letter) && graphemes.next().is_none()
+}
+
+/// Returns true if the text has cased letters and all of them are uppercase.
+pub fn is_all_uppercase(text: &str) -> bool {
+    text == text.to_uppercase() && text != text.to_lowercase()
+}
+
+pub fn is_lowercase(character: &str) -> bool {
+    character == character.to_lowercase() && character != character.to_uppercase()
+}
+
+pub fn titlecase_first_letter(word: &str) -> String {
     let mut chars = word.graphemes(true);
     match chars.next() {
         None => panic!("Passing empty words"),
         Some(first_char) => {
-            let mut res = first_char.to_uppercase();
+            let mut first = first_char.chars();
+            let mut res = first.next().map(titlecase).unwrap_or_default();
+            res.push_str(first.as_str());
             res.push_str(chars.as_str());
             res
         }
     }
 }
 
+/// Returns the Unicode titlecase mapping of a character. It differs from the uppercase mapping for digraphs,
+/// ligatures, `ß`, Greek letters with a iota subscript and Georgian letters.
+pub fn titlecase(c: char) -> String {
+    let title = match c {
+        'Ǆ'..='ǆ' => "ǅ",
+        'Ǉ'..='ǉ' => "ǈ",
+        'Ǌ'..='ǌ' => "ǋ",
+        'Ǳ'..='ǳ' => "ǲ",
+        'ß' => "Ss",
+        'ﬀ' => "Ff",
+        'ﬁ' => "Fi",
+        'ﬂ' => "Fl",
+        'ﬃ' => "Ffi",
+        'ﬄ' => "Ffl",
+        'ﬅ' | 'ﬆ' => "St",
+        'և' => "Եւ",
+        'ﬓ' => "Մն",
+        'ﬔ' => "Մե",
+        'ﬕ' => "Մի",
+        'ﬖ' => "Վն",
+        'ﬗ' => "Մխ",
+        'ᾳ' => "ᾼ",
+        'ῃ' => "ῌ",
+        'ῳ' => "ῼ",
+        '\u{1fb2}' => "\u{1fba}\u{345}",
+        '\u{1fb4}' => "\u{386}\u{345}",
+        '\u{1fb7}' => "\u{391}\u{342}\u{345}",
+        '\u{1fc2}' => "\u{1fca}\u{345}",
+        '\u{1fc4}' => "\u{389}\u{345}",
+        '\u{1fc7}' => "\u{397}\u{342}\u{345}",
+        '\u{1ff2}' => "\u{1ffa}\u{345}",
+        '\u{1ff4}' => "\u{38f}\u{345}",
+        '\u{1ff7}' => "\u{3a9}\u{342}\u{345}",
+        // Greek letters with a iota subscript, whose uppercase spells the iota as a capital
+        '\u{1f80}'..='\u{1f87}' | '\u{1f90}'..='\u{1f97}' | '\u{1fa0}'..='\u{1fa7}' => {
+            return char::from_u32(c as u32 + 8).unwrap_or(c).to_string();
+        }
+        // already titlecase, and Georgian Mkhedruli letters, which are their own titlecase unlike their Mtavruli
+        // uppercase
+        '\u{1f88}'..='\u{1f8f}'
+        | '\u{1f98}'..='\u{1f9f}'
+        | '\u{1fa8}'..='\u{1faf}'
+        | 'ᾼ'
+        | 'ῌ'
+        | 'ῼ'
+        | '\u{10d0}'..='\u{10fa}'
+        | '\u{10fd}'..='\u{10ff}' => return c.to_string(),
+        _ => return c.to_uppercase().collect(),
+    };
+    title.to_owned()
+}
+
 #[cfg(test)]
 mod utils_tests {
     mod uppercase_related {
@@ -103,27 +377,52 @@ mod utils_tests {
 
         #[test]
         #[should_panic]
-        fn test_uppercase_first_letter() {
+        fn test_titlecase_first_letter() {
             assert_eq!(
-                uppercase_first_letter("ßenevolent"),
-                "SSenevolent".to_string()
+                titlecase_first_letter("ßenevolent"),
+                "Ssenevolent".to_string()
             );
-            assert_eq!(uppercase_first_letter("ṁatsuri"), "Ṁatsuri".to_string());
+            assert_eq!(titlecase_first_letter("ṁatsuri"), "Ṁatsuri".to_string());
             assert_eq!(
-                uppercase_first_letter("夏色まつり"),
+                titlecase_first_letter("夏色まつり"),
                 "夏色まつり".to_string()
             );
             assert_eq!(
-                uppercase_first_letter("normalForOnce"),
+                titlecase_first_letter("normalForOnce"),
                 "NormalForOnce".to_string()
             );
-            assert_eq!(uppercase_first_letter("?"), "?".to_string());
-            uppercase_first_letter("");
+            assert_eq!(titlecase_first_letter("?"), "?".to_string());
+            titlecase_first_letter("");
+        }
+
+        #[test]
+        fn test_titlecase() {
+            let input = [
+                ('ǆ', "ǅ"),
+                ('Ǆ', "ǅ"),
+                ('ǉ', "ǈ"),
+                ('ß', "Ss"),
+                ('ﬁ', "Fi"),
+                ('ᾳ', "ᾼ"),
+                ('ᾀ', "ᾈ"),
+                ('ᾈ', "ᾈ"),
+                ('ა', "ა"),
+                ('ж', "Ж"),
+                ('a', "A"),
+                ('1', "1"),
+            ];
+
+            for (c, expected) in input {
+                assert_eq!(titlecase(c), expected, "{c}");
+            }
+            assert_eq!(titlecase_first_letter("ǆungla"), "ǅungla");
+            assert_eq!(titlecase_first_letter("a\u{301}b"), "A\u{301}b");
         }
     }
 
     mod test_slice_words {
         use crate::utils::*;
+        use crate::{DigitBoundary, Options, Segmentation, Separators};
 
         use std::vec;
 
@@ -152,7 +451,7 @@ mod utils_tests {
             let mut output: Vec<Vec<String>> = vec![];
 
             for s in input {
-                output.push(slice_into_words(s));
+                output.push(slice_into_words(&s, &Options::default()));
             }
 
             assert_eq!(output, expected_output);
@@ -184,7 +483,7 @@ mod utils_tests {
             let mut output: Vec<Vec<String>> = vec![];
 
             for s in input {
-                output.push(slice_into_words(s));
+                output.push(slice_into_words(&s, &Options::default()));
             }
 
             assert_eq!(output, expected_output);
@@ -223,7 +522,7 @@ mod utils_tests {
             let mut output: Vec<Vec<String>> = vec![];
 
             for s in input {
-                output.push(slice_into_words(s));
+                output.push(slice_into_words(&s, &Options::default()));
             }
 
             assert_eq!(output, expected_output);
@@ -260,10 +559,141 @@ mod utils_tests {
             let mut output: Vec<Vec<String>> = vec![];
 
             for s in input {
-                output.push(slice_into_words(s));
+                output.push(slice_into_words(&s, &Options::default()));
             }
 
             assert_eq!(output, expected_output);
         }
+
+        #[test]
+        fn slice_words_by_acronyms() {
+            let options = Options::new().segmentation(Segmentation::Acronym);
+            let input = [
+                "HTTPServer",
+                "parseJSONResponse",
+                "IOError",
+                "LONG_random_text",
+                "ÄÖÜTest",
+                "getURL",
+                "ATest",
+            ];
+            let expected_output = [
+                vec!["http", "server"],
+                vec!["parse", "json", "response"],
+                vec!["io", "error"],
+                vec!["long", "random", "text"],
+                vec!["äöü", "test"],
+                vec!["get", "url"],
+                vec!["a", "test"],
+            ];
+
+            for (s, expected) in input.iter().zip(expected_output) {
+                assert_eq!(slice_into_words(s, &options), expected);
+            }
+        }
+
+        #[test]
+        fn slice_words_by_digits() {
+            let input = [
+                "version2Update",
+                "utf8Decoder",
+                "ipv4address",
+                "Base64",
+                "ver٣beta",
+            ];
+            let expected_output = [
+                (
+                    DigitBoundary::Never,
+                    [
+                        vec!["version2", "update"],
+                        vec!["utf8", "decoder"],
+                        vec!["ipv4address"],
+                        vec!["base64"],
+                        vec!["ver٣beta"],
+                    ],
+                ),
+                (
+                    DigitBoundary::BeforeDigits,
+                    [
+                        vec!["version", "2", "update"],
+                        vec!["utf", "8", "decoder"],
+                        vec!["ipv", "4address"],
+                        vec!["base", "64"],
+                        vec!["ver", "٣beta"],
+                    ],
+                ),
+                (
+                    DigitBoundary::AfterDigits,
+                    [
+                        vec!["version2", "update"],
+                        vec!["utf8", "decoder"],
+                        vec!["ipv4", "address"],
+                        vec!["base64"],
+                        vec!["ver٣", "beta"],
+                    ],
+                ),
+                (
+                    DigitBoundary::Both,
+                    [
+                        vec!["version", "2", "update"],
+                        vec!["utf", "8", "decoder"],
+                        vec!["ipv", "4", "address"],
+                        vec!["base", "64"],
+                        vec!["ver", "٣", "beta"],
+                    ],
+                ),
+            ];
+
+            for (boundary, expected) in expected_output {
+                let options = Options::new().digit_boundary(boundary);
+                for (s, words) in input.iter().zip(expected) {
+                    assert_eq!(slice_into_words(s, &options), words);
+                }
+            }
+        }
+
+        #[test]
+        fn slice_words_by_decimal_digits_only() {
+            let options = Options::new().digit_boundary(DigitBoundary::Both);
+            assert_eq!(slice_into_words("m²", &options), vec!["m²"]);
+            assert_eq!(slice_into_words("size½", &options), vec!["size½"]);
+            assert_eq!(slice_into_words("step①", &options), vec!["step①"]);
+            assert_eq!(slice_into_words("x𝟘y", &options), vec!["x", "𝟘", "y"]);
+
+            for numeric in ["²", "½", "①", "Ⅻ"] {
+                assert!(!is_digit(numeric), "{numeric}");
+            }
+            for digit in ["0", "٣", "३", "０", "𝟘"] {
+                assert!(is_digit(digit), "{digit}");
+            }
+            assert!(is_letter("Ⅻ"));
+        }
+
+        #[test]
+        fn slice_words_by_custom_separators() {
+            let options = Options::new().separators(Separators::new().with("+").with("|"));
+            assert_eq!(
+                slice_into_words("god+matsuri|ahihihi", &options),
+                vec!["god", "matsuri", "ahihihi"]
+            );
+
+            let options = Options::new().separators(Separators::new().without("."));
+            assert_eq!(
+                slice_into_words("v1.2.3 beta", &options),
+                vec!["v1.2.3", "beta"]
+            );
+
+            let options = Options::new().separators(Separators::none());
+            assert_eq!(
+                slice_into_words("god matsuri", &options),
+                vec!["god matsuri"]
+            );
+
+            let options = Options::new().separators(Separators::from_fn(|g| g == ":").with("?"));
+            assert_eq!(
+                slice_into_words("god:matsuri?a b", &options),
+                vec!["god", "matsuri", "a b"]
+            );
+        }
     }
 }
@@ -0,0 +1,391 @@
+use std::fmt;
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::detect::{Shape, WordShape};
+use crate::{utils, Case, Options, ReCase};
+
+/// The kind of a [`Violation`] of a case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ViolationKind {
+    /// A separator before the first word.
+    LeadingSeparator,
+    /// A separator after the last word.
+    TrailingSeparator,
+    /// The separator of the case repeated between two words.
+    DoubleSeparator,
+    /// A separator that is not the one of the case.
+    WrongSeparator,
+    /// Two words that the case separates are joined.
+    MissingSeparator,
+    /// A letter that should be lowercase.
+    UnexpectedCapital,
+    /// A letter that should be uppercase.
+    MissingCapital,
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ViolationKind::LeadingSeparator => "leading separator",
+            ViolationKind::TrailingSeparator => "trailing separator",
+            ViolationKind::DoubleSeparator => "double separator",
+            ViolationKind::WrongSeparator => "wrong separator",
+            ViolationKind::MissingSeparator => "missing separator",
+            ViolationKind::UnexpectedCapital => "unexpected capital",
+            ViolationKind::MissingCapital => "missing capital",
+        })
+    }
+}
+
+/// A part of an input that does not follow a case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// The byte range of the violating part of the input. It is empty for a missing separator, which is located
+    /// where the separator should be.
+    pub span: Range<usize>,
+    /// What is wrong with the part.
+    pub kind: ViolationKind,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)
+    }
+}
+
+/// The result of validating an input against a case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Validation {
+    /// The parts of the input that do not follow the case, in order.
+    pub violations: Vec<Violation>,
+    /// The input converted into the case by `ReCase`, or the input itself if it is valid.
+    pub suggestion: String,
+}
+
+impl Validation {
+    /// Returns true if the input follows the case.
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Validates that an input is written in a case, returning the violating parts of the input and a corrected
+/// version of it. Runs of capitals are accepted as words of `camelCase` and `PascalCase` inputs, like
+/// [`Case::detect()`](Case::detect) does.
+/// ## Example
+/// ```
+/// use recase::{validate, Case, ViolationKind};
+///
+/// let validation = validate("_foo__barBaz", Case::Snake);
+/// let kinds: Vec<ViolationKind> = validation.violations.iter().map(|v| v.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     vec![
+///         ViolationKind::LeadingSeparator,
+///         ViolationKind::DoubleSeparator,
+///         ViolationKind::MissingSeparator,
+///         ViolationKind::UnexpectedCapital,
+///     ]
+/// );
+/// assert_eq!(validation.violations[1].span, 4..6);
+/// assert_eq!(validation.suggestion, String::from("foo_bar_baz"));
+///
+/// assert!(validate("foo_bar", Case::Snake).is_valid());
+/// ```
+pub fn validate(input: &str, case: Case) -> Validation {
+    let violations = match case.shape() {
+        Some(shape) => shape_violations(input, case, &shape),
+        None => alternating_violations(input),
+    };
+
+    let suggestion = if violations.is_empty() {
+        input.to_owned()
+    } else {
+        let options = Options::detection();
+        ReCase::with_options(input, options).to_case(case)
+    };
+
+    Validation {
+        violations,
+        suggestion,
+    }
+}
+
+fn shape_violations(input: &str, case: Case, shape: &Shape) -> Vec<Violation> {
+    let options = Options::detection();
+    let spans = utils::slice_into_spans(input, &options);
+    let mut violations = vec![];
+
+    let Some((first, last)) = spans.first().zip(spans.last()) else {
+        return violations;
+    };
+    if first.start > 0 {
+        violations.push(Violation {
+            span: 0..first.start,
+            kind: ViolationKind::LeadingSeparator,
+        });
+    }
+
+    for (i, span) in spans.iter().enumerate() {
+        if i > 0 {
+            let gap = spans[i - 1].end..span.start;
+            if let Some(kind) = gap_violation(&input[gap.clone()], shape.separator) {
+                violations.push(Violation { span: gap, kind });
+            }
+        }
+
+        let expected = if i == 0 {
+            shape.first_word
+        } else {
+            shape.other_words
+        };
+        let allow_acronyms = matches!(case, Case::Camel | Case::Pascal);
+        word_violations(input, span, expected, allow_acronyms, &mut violations);
+    }
+
+    if last.end < input.len() {
+        violations.push(Violation {
+            span: last.end..input.len(),
+            kind: ViolationKind::TrailingSeparator,
+        });
+    }
+
+    violations
+}
+
+fn gap_violation(gap: &str, separator: &str) -> Option<ViolationKind> {
+    if gap == separator {
+        None
+    } else if gap.is_empty() {
+        Some(ViolationKind::MissingSeparator)
+    } else if !separator.is_empty() && gap.graphemes(true).all(|g| g == separator) {
+        Some(ViolationKind::DoubleSeparator)
+    } else {
+        Some(ViolationKind::WrongSeparator)
+    }
+}
+
+fn word_violations(
+    input: &str,
+    span: &Range<usize>,
+    expected: WordShape,
+    allow_acronyms: bool,
+    violations: &mut Vec<Violation>,
+) {
+    let word = &input[span.clone()];
+    if allow_acronyms && expected == WordShape::Title && WordShape::of(word) == WordShape::Upper {
+        return;
+    }
+
+    let mut first_cased = true;
+    for (offset, g) in word.grapheme_indices(true) {
+        let is_upper = utils::is_capital(g);
+        if !is_upper && !utils::is_lowercase(g) {
+            continue;
+        }
+        let should_be_upper = match expected {
+            WordShape::Upper => true,
+            WordShape::Title => first_cased,
+            _ => false,
+        };
+        first_cased = false;
+
+        if is_upper != should_be_upper {
+            let kind = if is_upper {
+                ViolationKind::UnexpectedCapital
+            } else {
+                ViolationKind::MissingCapital
+            };
+            push_merged(
+                violations,
+                span.start + offset..span.start + offset + g.len(),
+                kind,
+            );
+        }
+    }
+}
+
+/// Pushes a violation, merging it with the previous one if they are adjacent and of the same kind.
+fn push_merged(violations: &mut Vec<Violation>, span: Range<usize>, kind: ViolationKind) {
+    if let Some(last) = violations.last_mut() {
+        if last.kind == kind && last.span.end == span.start {
+            last.span.end = span.end;
+            return;
+        }
+    }
+    violations.push(Violation { span, kind });
+}
+
+fn alternating_violations(input: &str) -> Vec<Violation> {
+    let options = Options::new();
+    let mut violations = vec![];
+    let mut uppercase = true;
+    let mut gap: Option<Range<usize>> = None;
+
+    let push_gap = |gap: Range<usize>, violations: &mut Vec<Violation>, is_last: bool| {
+        let kind = if gap.start == 0 {
+            Some(ViolationKind::LeadingSeparator)
+        } else if is_last {
+            Some(ViolationKind::TrailingSeparator)
+        } else {
+            gap_violation(&input[gap.clone()], " ")
+        };
+        if let Some(kind) = kind {
+            violations.push(Violation { span: gap, kind });
+        }
+    };
+
+    for (offset, g) in input.grapheme_indices(true) {
+        if options.separators.contains(g) {
+            let start = gap.map_or(offset, |gap| gap.start);
+            gap = Some(start..offset + g.len());
+            continue;
+        }
+        if let Some(gap) = gap.take() {
+            push_gap(gap, &mut violations, false);
+        }
+
+        uppercase = !uppercase;
+        let is_upper = utils::is_capital(g);
+        if (is_upper || utils::is_lowercase(g)) && is_upper != uppercase {
+            let kind = if is_upper {
+                ViolationKind::UnexpectedCapital
+            } else {
+                ViolationKind::MissingCapital
+            };
+            violations.push(Violation {
+                span: offset..offset + g.len(),
+                kind,
+            });
+        }
+    }
+    if let Some(gap) = gap {
+        push_gap(gap, &mut violations, true);
+    }
+
+    violations
+}
+
+#[cfg(test)]
+mod validate_tests {
+    use crate::{validate, Case, Casing, ViolationKind};
+
+    fn kinds(input: &str, case: Case) -> Vec<(ViolationKind, std::ops::Range<usize>)> {
+        validate(input, case)
+            .violations
+            .into_iter()
+            .map(|v| (v.kind, v.span))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_inputs() {
+        let input = [
+            ("foo_bar", Case::Snake),
+            ("fooBar", Case::Camel),
+            ("parseJSON", Case::Camel),
+            ("HTTPServer", Case::Pascal),
+            ("FOO_BAR_2", Case::UpperSnake),
+            ("Foo-Bar", Case::Header),
+            ("göd mätsuri", Case::Normal),
+            ("fOo BaR", Case::Alternating),
+            ("", Case::Snake),
+        ];
+
+        for (s, case) in input {
+            let validation = validate(s, case);
+            assert!(validation.is_valid(), "{s}: {:?}", validation.violations);
+            assert_eq!(validation.suggestion, s);
+        }
+    }
+
+    #[test]
+    fn test_violations() {
+        assert_eq!(
+            kinds("foo-bar_", Case::Snake),
+            vec![
+                (ViolationKind::WrongSeparator, 3..4),
+                (ViolationKind::TrailingSeparator, 7..8),
+            ]
+        );
+        assert_eq!(
+            kinds("FooBAR", Case::Snake),
+            vec![
+                (ViolationKind::UnexpectedCapital, 0..1),
+                (ViolationKind::MissingSeparator, 3..3),
+                (ViolationKind::UnexpectedCapital, 3..6),
+            ]
+        );
+        assert_eq!(
+            kinds("foo_bar", Case::Pascal),
+            vec![
+                (ViolationKind::MissingCapital, 0..1),
+                (ViolationKind::WrongSeparator, 3..4),
+                (ViolationKind::MissingCapital, 4..5),
+            ]
+        );
+        assert_eq!(
+            kinds("FOO_bar", Case::UpperSnake),
+            vec![(ViolationKind::MissingCapital, 4..7)]
+        );
+        assert_eq!(
+            kinds("Foo Bar", Case::Sentence),
+            vec![(ViolationKind::UnexpectedCapital, 4..5)]
+        );
+        assert_eq!(
+            kinds(" fOO  BaR", Case::Alternating),
+            vec![
+                (ViolationKind::LeadingSeparator, 0..1),
+                (ViolationKind::UnexpectedCapital, 3..4),
+                (ViolationKind::DoubleSeparator, 4..6),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_titlecase_letters() {
+        assert_eq!(
+            kinds("ǅungla", Case::Snake),
+            vec![(ViolationKind::UnexpectedCapital, 0..2)]
+        );
+        assert_eq!(validate("ǅungla", Case::Snake).suggestion, "ǆungla");
+        assert!(validate("ǅungla", Case::Pascal).is_valid());
+        assert!(validate("ǆunglaǈubav", Case::Camel).is_valid());
+        assert!(!"ǅungla".is_snake_case());
+        assert_eq!(
+            validate("ǆunglaǈubav", Case::Snake).suggestion,
+            "ǆungla_ǉubav"
+        );
+    }
+
+    #[test]
+    fn test_suggestions() {
+        assert_eq!(validate("fooBar", Case::Snake).suggestion, "foo_bar");
+        assert_eq!(
+            validate("__HTTP_server", Case::Pascal).suggestion,
+            "HttpServer"
+        );
+        assert_eq!(validate("Foo Bar", Case::Sentence).suggestion, "Foo bar");
+    }
+
+    #[test]
+    fn test_casing_validators() {
+        assert!("foo_bar".is_snake_case());
+        assert!(!"foo_Bar".is_snake_case());
+        assert!("fooBar".is_camel_case());
+        assert!("FooBar".is_pascal_case());
+        assert!("foo-bar".is_kebab_case());
+        assert!("foo.bar".is_dot_case());
+        assert!("foo/bar".is_path_case());
+        assert!("foo\\bar".is_windows_path_case());
+        assert!("foo bar".is_normal_case());
+        assert!("Foo bar".is_sentence_case());
+        assert!("Foo Bar".is_title_case());
+        assert!("Foo-Bar".is_header_case());
+        assert!("FOO_BAR".is_upper_snake_case());
+        assert!("fOo BaR".is_alternating_case());
+        assert!("foo.bar".is_case(Case::Dot));
+    }
+}